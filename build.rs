@@ -0,0 +1,54 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Returns the sorted names of the entries in `dir` that are `prefix`
+/// followed by `digits` ASCII digits and then `suffix`.
+fn matching_entries(dir: &Path, prefix: &str, digits: usize, suffix: &str) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let n = name.strip_prefix(prefix)?.strip_suffix(suffix)?;
+            if n.len() == digits && n.chars().all(|c| c.is_ascii_digit()) {
+                Some(name[..name.len() - suffix.len()].to_string())
+            } else {
+                None
+            }
+        })
+        .collect();
+    names.sort();
+    names
+}
+
+/// Generates the `all_solutions()` list from the `src/days/yYYYY/dayNN.rs`
+/// files, so every implemented day of every year is registered without
+/// maintaining a list by hand. The modules themselves are declared in
+/// `src/days/mod.rs` and `src/days/yYYYY/mod.rs`, where `new` adds them, and
+/// each `dayNN` module must export a `DayNN` type implementing `Solver`.
+fn main() {
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    let days_dir = Path::new(&manifest_dir).join("src/days");
+    println!("cargo:rerun-if-changed=src/days");
+
+    let mut solutions = String::from("vec![\n");
+    for year in matching_entries(&days_dir, "y", 4, "") {
+        let year_dir = days_dir.join(&year);
+        println!("cargo:rerun-if-changed=src/days/{}", year);
+
+        for day in matching_entries(&year_dir, "day", 2, ".rs") {
+            let solver = day.replacen("day", "Day", 1);
+            writeln!(
+                solutions,
+                "    Solution::new::<days::{}::{}::{}>(),",
+                year, day, solver
+            )
+            .unwrap();
+        }
+    }
+    solutions.push(']');
+
+    fs::write(Path::new(&out_dir).join("solutions.rs"), solutions).unwrap();
+}
@@ -1,37 +1,53 @@
+use crate::solution::Solver;
+use crate::utils::parse::ParseError;
+
 pub struct Input {}
 
-pub fn parse_input(input: &str) -> Input {
-    input
-}
+pub struct Day00;
 
-#[allow(unused_variables)]
-pub fn part1(input: &Input) -> Option<u64> {
-    None
-}
+impl Solver for Day00 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
 
-#[allow(unused_variables)]
-pub fn part2(input: &Input) -> Option<u64> {
-    None
+    const TITLE: &'static str = "TODO";
+    const YEAR: u32 = 0;
+    const DAY: u32 = 0;
+
+    #[allow(unused_variables)]
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(Input {})
+    }
+
+    #[allow(unused_variables)]
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        None
+    }
+
+    #[allow(unused_variables)]
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        None
+    }
 }
 
 #[cfg(test)]
 pub mod tests {
     use super::*;
-    const TEST_INPUT: &str = include_str!("../../input/day00/test.txt");
+    const TEST_INPUT: &str = include_str!("../../../input/0000/day00/test.txt");
     #[test]
     fn test_day00_part1() {
-        let input = parse_input(TEST_INPUT);
+        let input = Day00::parse(TEST_INPUT).unwrap();
 
-        let resp = part1(&input);
+        let resp = Day00::part1(&input);
 
         assert_eq!(resp, None);
     }
 
     #[test]
     fn test_day00_part2() {
-        let input = parse_input(TEST_INPUT);
+        let input = Day00::parse(TEST_INPUT).unwrap();
 
-        let resp = part2(&input);
+        let resp = Day00::part2(&input);
 
         assert_eq!(resp, None);
     }
@@ -0,0 +1,97 @@
+use crate::solution::Solver;
+use crate::utils::parse::{split_record, ParseError};
+use std::collections::HashMap;
+
+pub struct Day01;
+
+impl Solver for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Historian Hysteria";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 1;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+        for line in input.lines() {
+            let [l, r] = split_record(input, line, ' ')?;
+            left.push(l);
+            right.push(r);
+        }
+
+        left.sort();
+        right.sort();
+
+        Ok((left, right))
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let iter = input.0.iter().zip(input.1.iter());
+        let mut sum = 0;
+
+        for (l, r) in iter {
+            let diff = if l > r { l - r } else { r - l };
+            sum += diff;
+        }
+
+        Some(sum as u64)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut counts = HashMap::new();
+        let (left, right) = input;
+
+        for n in right.iter() {
+            counts.entry(n).and_modify(|v| *v += 1).or_insert(1);
+        }
+
+        let mut sum = 0;
+        for n in left.iter() {
+            let right_count = counts.get(n).unwrap_or(&0);
+
+            sum += n * right_count;
+        }
+
+        Some(sum as u64)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day01/test.txt");
+    #[test]
+    fn test_day00_part1() {
+        let input = Day01::parse(TEST_INPUT).unwrap();
+
+        let resp = Day01::part1(&input);
+
+        assert_eq!(resp, Some(11));
+    }
+
+    #[test]
+    fn test_day00_part2() {
+        let input = Day01::parse(TEST_INPUT).unwrap();
+
+        let resp = Day01::part2(&input);
+
+        assert_eq!(resp, Some(31));
+    }
+
+    #[test]
+    fn test_day01_parse_error() {
+        let err = Day01::parse("3   4\n4\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_day01_stray_token() {
+        let err = Day01::parse("3   4\n3 x 4\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
@@ -0,0 +1,118 @@
+use crate::solution::Solver;
+use crate::utils::parse::{fields, ParseError};
+
+pub struct Day02;
+
+impl Solver for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Red-Nosed Reports";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 2;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut reports = Vec::new();
+
+        for line in input.lines() {
+            let report = fields(input, line, ' ').collect::<Result<_, _>>()?;
+            reports.push(report);
+        }
+
+        Ok(reports)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let count = input.iter().filter(|report| is_safe(report, false)).count();
+
+        Some(count as u64)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let count = input.iter().filter(|report| is_safe(report, true)).count();
+
+        Some(count as u64)
+    }
+}
+
+/// Whether the levels of `report` all increase or all decrease by 1 to 3,
+/// optionally after the Problem Dampener removes a single level.
+pub fn is_safe(report: &[i32], dampen: bool) -> bool {
+    [1, -1]
+        .into_iter()
+        .any(|sign| match first_unsafe(report, sign, None) {
+            None => true,
+            // Any removal that fixes the report must remove one of the first bad
+            // pair, since otherwise that pair stays adjacent. Checking just those
+            // two keeps this linear in the length of the report.
+            Some((a, b)) => {
+                dampen
+                    && (first_unsafe(report, sign, Some(a)).is_none()
+                        || first_unsafe(report, sign, Some(b)).is_none())
+            }
+        })
+}
+
+/// The indices of the first adjacent pair of levels, ignoring level `skip`,
+/// that does not step by 1 to 3 in the direction of `sign`.
+fn first_unsafe(report: &[i32], sign: i32, skip: Option<usize>) -> Option<(usize, usize)> {
+    let mut levels = report.iter().enumerate().filter(|&(i, _)| Some(i) != skip);
+    let (mut prev_i, mut prev) = levels.next()?;
+
+    for (i, level) in levels {
+        if !(1..=3).contains(&(sign * (level - prev))) {
+            return Some((prev_i, i));
+        }
+        (prev_i, prev) = (i, level);
+    }
+
+    None
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day02/test.txt");
+    #[test]
+    fn test_day00_part1() {
+        let input = Day02::parse(TEST_INPUT).unwrap();
+
+        let resp = Day02::part1(&input);
+
+        assert_eq!(resp, Some(1));
+    }
+
+    #[test]
+    fn test_day00_part2() {
+        let input = Day02::parse(TEST_INPUT).unwrap();
+
+        let resp = Day02::part2(&input);
+
+        assert_eq!(resp, Some(4));
+    }
+
+    #[test]
+    fn test_day02_dampener_removes_one_level() {
+        // First, middle and last levels, increasing and decreasing.
+        assert!(is_safe(&[9, 1, 2, 3], true));
+        assert!(is_safe(&[1, 2, 9, 3, 4], true));
+        assert!(is_safe(&[1, 2, 3, 9], true));
+        assert!(is_safe(&[0, 9, 8, 7], true));
+        assert!(is_safe(&[9, 8, 8, 7], true));
+        assert!(is_safe(&[9, 8, 7, 7], true));
+
+        assert!(!is_safe(&[9, 1, 2, 3], false));
+        assert!(!is_safe(&[1, 9, 2, 9, 3], true));
+        assert!(!is_safe(&[1, 5, 9], true));
+    }
+
+    #[test]
+    fn test_day02_stray_token() {
+        let err = Day02::parse("1 2 3\n1 2 x 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 5));
+
+        let err = Day02::parse("6-4 3\n").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 1));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::solution::Solver;
+use crate::utils::parse::{parse, ParseError};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Mul(i32, i32),
+    Do,
+    Dont,
+}
+
+pub struct Day03;
+
+impl Solver for Day03 {
+    type Input = Vec<Instruction>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Mull It Over";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 3;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut instructions = Vec::new();
+        for (i, _) in input.match_indices(['m', 'd']) {
+            let rest = &input[i..];
+            if rest.starts_with("do()") {
+                instructions.push(Instruction::Do);
+            } else if rest.starts_with("don't()") {
+                instructions.push(Instruction::Dont);
+            } else if let Some((x, y)) = mul_args(rest) {
+                instructions.push(Instruction::Mul(parse(input, x)?, parse(input, y)?));
+            }
+        }
+
+        Ok(instructions)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let sum: i32 = input
+            .iter()
+            .map(|instr| match instr {
+                Instruction::Mul(x, y) => x * y,
+                _ => 0,
+            })
+            .sum();
+
+        Some(sum as u64)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut enabled = 1;
+
+        let sum: i32 = input
+            .iter()
+            .map(|instr| match instr {
+                Instruction::Mul(x, y) => enabled * x * y,
+                Instruction::Do => {
+                    enabled = 1;
+                    0
+                }
+                Instruction::Dont => {
+                    enabled = 0;
+                    0
+                }
+            })
+            .sum();
+
+        Some(sum as u64)
+    }
+}
+
+// The two numbers of a `mul(X,Y)` at the start of `s`, if it starts with one.
+fn mul_args(s: &str) -> Option<(&str, &str)> {
+    let (x, s) = digits(s.strip_prefix("mul(")?)?;
+    let (y, s) = digits(s.strip_prefix(',')?)?;
+    s.starts_with(')').then_some((x, y))
+}
+
+// The run of ASCII digits at the start of `s` and what follows it, or `None`
+// if `s` does not start with a digit.
+fn digits(s: &str) -> Option<(&str, &str)> {
+    let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    (end > 0).then(|| s.split_at(end))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day03/test.txt");
+    #[test]
+    fn test_day03_part1() {
+        let input = Day03::parse(TEST_INPUT).unwrap();
+
+        let resp = Day03::part1(&input);
+
+        assert_eq!(resp, Some(161));
+    }
+
+    #[test]
+    fn test_day03_part2() {
+        let input = Day03::parse(TEST_INPUT).unwrap();
+
+        let resp = Day03::part2(&input);
+
+        assert_eq!(resp, Some(48));
+    }
+
+    #[test]
+    fn test_day03_malformed_mul() {
+        let input =
+            Day03::parse("mul(1,2)mul(3,)mul(,4)mul(5,6]mul(+7,8)mul ( 2 , 4 )mul(9,10)mul(1")
+                .unwrap();
+
+        assert_eq!(Day03::part1(&input), Some(92));
+    }
+}
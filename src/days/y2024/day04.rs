@@ -0,0 +1,77 @@
+use crate::solution::Solver;
+use crate::utils::grid::Grid;
+use crate::utils::parse::ParseError;
+use crate::utils::point::{Direction, Point};
+
+pub struct Day04;
+
+impl Solver for Day04 {
+    type Input = Grid<char>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Ceres Search";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 4;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let sum = input
+            .find_all(&'X')
+            .flat_map(|pos| Direction::ALL.map(|direction| (pos, direction)))
+            .filter(|&(pos, direction)| {
+                let word = input.ray(pos, direction).take(4).copied();
+                word.eq("XMAS".chars())
+            })
+            .count();
+
+        Some(sum as u64)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let sum = input
+            .find_all(&'A')
+            .filter(|&pos| {
+                is_mas(input, pos, Direction::NorthWest) && is_mas(input, pos, Direction::NorthEast)
+            })
+            .count();
+
+        Some(sum as u64)
+    }
+}
+
+// Whether the diagonal through `pos` from `direction` to its reverse reads
+// MAS either way round.
+fn is_mas(grid: &Grid<char>, pos: Point, direction: Direction) -> bool {
+    let ends = (
+        grid.get(pos.step(direction)),
+        grid.get(pos.step(direction.reverse())),
+    );
+    matches!(ends, (Some('M'), Some('S')) | (Some('S'), Some('M')))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day04/test.txt");
+    #[test]
+    fn test_day04_part1() {
+        let input = Day04::parse(TEST_INPUT).unwrap();
+
+        let resp = Day04::part1(&input);
+
+        assert_eq!(resp, Some(18));
+    }
+
+    #[test]
+    fn test_day04_part2() {
+        let input = Day04::parse(TEST_INPUT).unwrap();
+
+        let resp = Day04::part2(&input);
+
+        assert_eq!(resp, Some(9));
+    }
+}
@@ -0,0 +1,130 @@
+use crate::solution::Solver;
+use crate::utils::parse::{fields, sections, split_record, ParseError};
+use crate::utils::toposort::Precedence;
+
+pub struct Input {
+    rules: Precedence<i32>,
+    updates: Vec<Vec<i32>>,
+    /// Every update that breaks the rules, reordered so that it follows them.
+    reordered: Vec<Vec<i32>>,
+}
+
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Print Queue";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 5;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut sections = sections(input);
+        let rules = sections.next().unwrap_or_default();
+        let updates = sections.next().unwrap_or_default();
+
+        let mut precedence = Precedence::default();
+        for line in rules.lines() {
+            let [first, second] = split_record::<i32, 2>(input, line, '|')?;
+            precedence.insert(first, second);
+        }
+
+        let mut parsed = Vec::new();
+        let mut reordered = Vec::new();
+        for line in updates.lines() {
+            let update: Vec<i32> = fields(input, line, ',').collect::<Result<_, _>>()?;
+            // Rules that form a cycle among an update's pages leave it no
+            // correct order, so the input cannot be solved.
+            if !precedence.is_sorted(&update) {
+                let sorted = precedence
+                    .sort(&update)
+                    .map_err(|e| ParseError::at(input, line, e.to_string()))?;
+                reordered.push(sorted);
+            }
+            parsed.push(update);
+        }
+
+        Ok(Input {
+            rules: precedence,
+            updates: parsed,
+            reordered,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let sum = input
+            .updates
+            .iter()
+            .filter(|update| input.rules.is_sorted(update))
+            .map(|update| middle(update))
+            .sum();
+
+        Some(sum)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let sum = input.reordered.iter().map(|update| middle(update)).sum();
+
+        Some(sum)
+    }
+}
+
+fn middle(update: &[i32]) -> u64 {
+    update[update.len() / 2] as u64
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day05/test.txt");
+    #[test]
+    fn test_day05_part1() {
+        let input = Day05::parse(TEST_INPUT).unwrap();
+
+        let resp = Day05::part1(&input);
+
+        assert_eq!(resp, Some(143));
+    }
+
+    #[test]
+    fn test_day05_part2() {
+        let input = Day05::parse(TEST_INPUT).unwrap();
+
+        let resp = Day05::part2(&input);
+
+        assert_eq!(resp, Some(123));
+    }
+
+    #[test]
+    fn test_day05_reordered_updates() {
+        let input = Day05::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            input.reordered,
+            vec![
+                vec![97, 75, 47, 61, 53],
+                vec![61, 29, 13],
+                vec![97, 75, 47, 29, 13],
+            ]
+        );
+    }
+
+    #[test]
+    fn test_day05_rule_cycle_is_reported() {
+        let err = Day05::parse("1|2\n2|1\n\n1,3\n2,1\n").err().unwrap();
+
+        assert_eq!((err.line, err.column), (5, 1));
+        assert_eq!(err.message, "ordering has a cycle among [2, 1]");
+    }
+
+    #[test]
+    fn test_day05_stray_token() {
+        let err = Day05::parse("47|5x\n\n75,47\n").err().unwrap();
+        assert_eq!((err.line, err.column), (1, 4));
+
+        let err = Day05::parse("47|53\n\n75,4x,61\n").err().unwrap();
+        assert_eq!((err.line, err.column), (3, 4));
+    }
+}
@@ -0,0 +1,174 @@
+use crate::solution::Solver;
+use crate::utils::grid::Grid;
+use crate::utils::parse::ParseError;
+use crate::utils::point::{Direction, Point};
+use std::collections::HashSet;
+
+#[derive(Copy, Clone, Debug)]
+pub struct Guard {
+    pos: Point,
+    direction: Direction,
+}
+
+#[derive(Debug)]
+pub struct Input {
+    grid: Grid<char>,
+    start: Guard,
+}
+
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Guard Gallivant";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 6;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut grid = Grid::parse(input)?;
+        let start = grid
+            .iter()
+            .find_map(|(pos, &c)| Direction::from_char(c).map(|direction| Guard { pos, direction }))
+            .ok_or_else(|| ParseError::at_offset(input, 0, "no guard in the grid"))?;
+
+        grid[start.pos] = '.';
+        Ok(Input { grid, start })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        let mut grid = input.grid.clone();
+
+        let mut sum = 0;
+        let mut curr = input.start;
+        loop {
+            if grid[curr.pos] == '.' {
+                sum += 1;
+                grid[curr.pos] = 'X';
+            }
+
+            let next = curr.pos.step(curr.direction);
+            match grid.get(next) {
+                Some('#') => {
+                    curr.direction = curr.direction.turn_right();
+                }
+                Some(_) => {
+                    curr.pos = next;
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        Some(sum)
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        let mut grid = input.grid.clone();
+
+        // Strategy: we don't care about putting down on obstacle unless it'd be
+        // placed on the original path, so we can do one outer-walk through the original
+        // path. Everytime we'd walk into an open space, begin a new sub-iteration as if
+        // an obstacle is there. We can reduce the walk down to only tracking the turns
+        // we need to take - any time we would make the same turn a 2nd time, we've made
+        // a loop
+
+        let mut sum = 0;
+        let mut curr = input.start;
+        loop {
+            grid[curr.pos] = 'X';
+
+            let next = curr.pos.step(curr.direction);
+            match grid.get(next) {
+                Some('#') => {
+                    curr.direction = curr.direction.turn_right();
+                }
+                Some('.') => {
+                    // Backtrack pattern on subiteration here.
+                    // Do the sub iteration here.
+                    grid[next] = '#';
+                    sum += backtrack(curr, &grid);
+
+                    // Restore original state.
+                    grid[next] = '.';
+
+                    // Continue as usual
+                    curr.pos = next;
+                }
+                Some('X') => {
+                    // We've already visited this square, not eligible for subiteration from this
+                    // direction
+                    // Continue as usual
+                    curr.pos = next;
+                }
+                Some(_) => {
+                    panic!("bad path");
+                }
+                None => {
+                    break;
+                }
+            }
+        }
+
+        Some(sum)
+    }
+}
+
+// Returns 1 if it finds a loop or 0 otherwise
+pub fn backtrack(start: Guard, grid: &Grid<char>) -> u64 {
+    let mut curr = start;
+    let mut turns = HashSet::new();
+
+    let mut next = curr.pos.step(curr.direction);
+
+    loop {
+        while matches!(grid.get(next), Some('.' | 'X')) {
+            curr.pos = next;
+            next = next.step(curr.direction);
+        }
+
+        match grid.get(next) {
+            Some('#') => {
+                let new_turn = turns.insert((curr.pos, curr.direction));
+                if !new_turn {
+                    return 1;
+                }
+
+                curr.direction = curr.direction.turn_right();
+                next = curr.pos.step(curr.direction);
+            }
+            None => {
+                return 0;
+            }
+            Some(_) => {
+                panic!("bad path");
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day06/test.txt");
+    #[test]
+    fn test_day06_part1() {
+        let input = Day06::parse(TEST_INPUT).unwrap();
+
+        let resp = Day06::part1(&input);
+
+        assert_eq!(resp, Some(41));
+    }
+
+    #[test]
+    fn test_day06_part2() {
+        let input = Day06::parse(TEST_INPUT).unwrap();
+
+        let resp = Day06::part2(&input);
+
+        assert_eq!(resp, Some(6));
+    }
+}
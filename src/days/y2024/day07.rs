@@ -0,0 +1,433 @@
+use crate::solution::Solver;
+use crate::utils::parse::{fields, parse, ParseError};
+use std::fmt;
+use std::ops::ControlFlow;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    pub const PART1: [Operator; 2] = [Operator::Add, Operator::Multiply];
+    pub const PART2: [Operator; 3] = [Operator::Add, Operator::Multiply, Operator::Concat];
+
+    /// `x` combined with `y`, or `None` if the result overflows.
+    pub fn apply(self, x: u64, y: u64) -> Option<u64> {
+        match self {
+            Operator::Add => x.checked_add(y),
+            Operator::Multiply => x.checked_mul(y),
+            Operator::Concat => concat(x, y),
+        }
+    }
+
+    /// The `x` for which `apply(x, y)` is `result`, or `None` if there is
+    /// none.
+    pub fn undo(self, result: u64, y: u64) -> Option<Operand> {
+        match self {
+            Operator::Add => result.checked_sub(y).map(Operand::Exactly),
+            // Anything times zero is zero, so any `x` will do.
+            Operator::Multiply if y == 0 => (result == 0).then_some(Operand::Any),
+            Operator::Multiply => result
+                .is_multiple_of(y)
+                .then(|| Operand::Exactly(result / y)),
+            Operator::Concat => {
+                let digits = y.checked_ilog10().map_or(1, |d| d + 1);
+                let shift = 10u64.checked_pow(digits)?;
+                (result % shift == y).then(|| Operand::Exactly(result / shift))
+            }
+        }
+    }
+}
+
+/// The value the numbers to the left of an operator must evaluate to.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Operand {
+    Exactly(u64),
+    /// Any value, as when the operator multiplies by zero.
+    Any,
+}
+
+impl Operand {
+    pub fn matches(self, value: u64) -> bool {
+        match self {
+            Operand::Exactly(target) => target == value,
+            Operand::Any => true,
+        }
+    }
+
+    fn undo(self, op: Operator, y: u64) -> Option<Operand> {
+        match self {
+            Operand::Exactly(target) => op.undo(target, y),
+            Operand::Any => Some(Operand::Any),
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Multiply => "*",
+            Operator::Concat => "||",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// The digits of `x` followed by the digits of `y`, or `None` on overflow.
+pub fn concat(x: u64, y: u64) -> Option<u64> {
+    let digits = y.checked_ilog10().map_or(1, |d| d + 1);
+    x.checked_mul(10u64.checked_pow(digits)?)?.checked_add(y)
+}
+
+#[derive(Debug)]
+pub struct Equation {
+    result: u64,
+    numbers: Vec<u64>,
+}
+
+impl Equation {
+    /// Whether some choice of `operators` between the numbers, evaluated left
+    /// to right, gives the result.
+    pub fn solvable(&self, operators: &[Operator]) -> bool {
+        solve(Operand::Exactly(self.result), &self.numbers, operators)
+    }
+
+    /// Every sequence of `operators` that solves the equation, each listed
+    /// left to right. There can be exponentially many, as when several
+    /// numbers are zero, so prefer `first_solution` unless all are needed.
+    pub fn solutions(&self, operators: &[Operator]) -> Vec<Vec<Operator>> {
+        let mut solutions = Vec::new();
+        self.visit_solutions(operators, |solution| {
+            solutions.push(solution);
+            ControlFlow::Continue(())
+        });
+        solutions
+    }
+
+    /// One sequence of `operators` that solves the equation, if any.
+    pub fn first_solution(&self, operators: &[Operator]) -> Option<Vec<Operator>> {
+        let mut first = None;
+        self.visit_solutions(operators, |solution| {
+            first = Some(solution);
+            ControlFlow::Break(())
+        });
+        first
+    }
+
+    /// The equation's numbers joined by `sequence`, e.g. `81 + 40 * 27`.
+    pub fn expression(&self, sequence: &[Operator]) -> String {
+        let mut expression = self.numbers[0].to_string();
+        for (op, n) in sequence.iter().zip(&self.numbers[1..]) {
+            expression.push_str(&format!(" {} {}", op, n));
+        }
+        expression
+    }
+
+    fn visit_solutions(
+        &self,
+        operators: &[Operator],
+        mut f: impl FnMut(Vec<Operator>) -> ControlFlow<()>,
+    ) {
+        let mut chosen = Vec::with_capacity(self.numbers.len());
+        let _ = visit(
+            Operand::Exactly(self.result),
+            &self.numbers,
+            operators,
+            &mut chosen,
+            &mut f,
+        );
+    }
+}
+
+pub struct Input {
+    equations: Vec<Equation>,
+}
+
+pub struct Day07;
+
+impl Solver for Day07 {
+    type Input = Input;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    const TITLE: &'static str = "Bridge Repair";
+    const YEAR: u32 = 2024;
+    const DAY: u32 = 7;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut equations = Vec::new();
+        for line in input.lines() {
+            let (result, numbers) = line
+                .split_once(':')
+                .ok_or_else(|| ParseError::after(input, line, "missing `:`"))?;
+            let result = parse::<u64>(input, result)?;
+            let numbers = fields(input, numbers, ' ').collect::<Result<_, _>>()?;
+
+            equations.push(Equation { result, numbers })
+        }
+
+        Ok(Input { equations })
+    }
+
+    fn part1(input: &Self::Input) -> Option<Self::Part1> {
+        Some(calibration(input, &Operator::PART1))
+    }
+
+    fn part2(input: &Self::Input) -> Option<Self::Part2> {
+        Some(calibration(input, &Operator::PART2))
+    }
+
+    fn explain(input: &Self::Input) -> Vec<String> {
+        input
+            .equations
+            .iter()
+            .map(|eq| {
+                // Only the first solution is searched for, since counting them
+                // all can take exponential time. The part 1 operators are a
+                // subset of part 2's, so a part 1 solution also solves part 2.
+                let part1 = eq.first_solution(&Operator::PART1);
+                let part2 = part1
+                    .clone()
+                    .or_else(|| eq.first_solution(&Operator::PART2));
+                let found = |solution: &Option<_>| match solution {
+                    Some(_) => "at least one",
+                    None => "none",
+                };
+
+                match &part2 {
+                    Some(sequence) => format!(
+                        "{} = {} (solutions: {} in part 1, {} in part 2)",
+                        eq.result,
+                        eq.expression(sequence),
+                        found(&part1),
+                        found(&part2)
+                    ),
+                    None => format!("{}: no solution", eq.result),
+                }
+            })
+            .collect()
+    }
+}
+
+fn calibration(input: &Input, operators: &[Operator]) -> u64 {
+    input
+        .equations
+        .iter()
+        .filter(|eq| eq.solvable(operators))
+        .map(|eq| eq.result)
+        .sum()
+}
+
+// Works from the last number back to the first: an operator can only have
+// produced `target` from the last number if it can be undone, which prunes
+// most branches immediately (a product must divide, a concatenation must end
+// in the right digits).
+fn solve(target: Operand, numbers: &[u64], operators: &[Operator]) -> bool {
+    match numbers.split_last() {
+        None => false,
+        Some((&first, [])) => target.matches(first),
+        Some((&last, rest)) => operators.iter().any(|&op| {
+            target
+                .undo(op, last)
+                .is_some_and(|target| solve(target, rest, operators))
+        }),
+    }
+}
+
+// The same search as `solve`, but passing every solution to `f` until it
+// breaks. `chosen` holds the operators picked so far, from the right.
+fn visit(
+    target: Operand,
+    numbers: &[u64],
+    operators: &[Operator],
+    chosen: &mut Vec<Operator>,
+    f: &mut impl FnMut(Vec<Operator>) -> ControlFlow<()>,
+) -> ControlFlow<()> {
+    match numbers.split_last() {
+        None => ControlFlow::Continue(()),
+        Some((&first, [])) if target.matches(first) => f(chosen.iter().rev().copied().collect()),
+        Some((_, [])) => ControlFlow::Continue(()),
+        Some((&last, rest)) => {
+            for &op in operators {
+                if let Some(target) = target.undo(op, last) {
+                    chosen.push(op);
+                    let flow = visit(target, rest, operators, chosen, f);
+                    chosen.pop();
+                    flow?;
+                }
+            }
+            ControlFlow::Continue(())
+        }
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    const TEST_INPUT: &str = include_str!("../../../input/2024/day07/test.txt");
+    #[test]
+    fn test_day07_part1() {
+        let input = Day07::parse(TEST_INPUT).unwrap();
+
+        let resp = Day07::part1(&input);
+
+        assert_eq!(resp, Some(3749));
+    }
+
+    #[test]
+    fn test_day07_part2() {
+        let input = Day07::parse(TEST_INPUT).unwrap();
+
+        let resp = Day07::part2(&input);
+
+        assert_eq!(resp, Some(11387));
+    }
+
+    #[test]
+    fn test_day07_concat_powers_of_ten() {
+        assert_eq!(concat(12, 0), Some(120));
+        assert_eq!(concat(12, 10), Some(1210));
+        assert_eq!(concat(12, 100), Some(12100));
+        assert_eq!(concat(0, 7), Some(7));
+        assert_eq!(concat(u64::MAX / 10, 10), None);
+        assert_eq!(concat(1, u64::MAX), None);
+    }
+
+    #[test]
+    fn test_day07_overflow_is_pruned() {
+        let input = Day07::parse("18446744073709551615: 4294967296 4294967296 1\n").unwrap();
+
+        assert_eq!(Day07::part1(&input), Some(0));
+    }
+
+    #[test]
+    fn test_day07_solutions() {
+        let input = Day07::parse(TEST_INPUT).unwrap();
+        let [_, eq3267, _, eq156, ..] = &input.equations[..] else {
+            panic!("expected at least five equations");
+        };
+
+        let expressions: Vec<String> = eq3267
+            .solutions(&Operator::PART1)
+            .iter()
+            .map(|s| eq3267.expression(s))
+            .collect();
+        assert_eq!(expressions, vec!["81 * 40 + 27", "81 + 40 * 27"]);
+
+        assert_eq!(eq156.first_solution(&Operator::PART1), None);
+        let solution = eq156.first_solution(&Operator::PART2).unwrap();
+        assert_eq!(eq156.expression(&solution), "15 || 6");
+    }
+
+    #[test]
+    fn test_day07_explain() {
+        let input = Day07::parse(TEST_INPUT).unwrap();
+
+        let lines = Day07::explain(&input);
+
+        assert_eq!(
+            lines[0],
+            "190 = 10 * 19 (solutions: at least one in part 1, at least one in part 2)"
+        );
+        assert_eq!(
+            lines[3],
+            "156 = 15 || 6 (solutions: none in part 1, at least one in part 2)"
+        );
+        assert_eq!(
+            lines[4],
+            "7290 = 6 * 8 || 6 * 15 (solutions: none in part 1, at least one in part 2)"
+        );
+        assert_eq!(lines[5], "161011: no solution");
+    }
+
+    #[test]
+    fn test_day07_explain_many_zeros() {
+        // Every one of the 3^39 operator sequences solves this, so explaining
+        // it must not try to enumerate them.
+        let input = Day07::parse(&format!("0:{}\n", " 0".repeat(40))).unwrap();
+
+        let lines = Day07::explain(&input);
+
+        assert!(lines[0].ends_with("(solutions: at least one in part 1, at least one in part 2)"));
+    }
+
+    // Tries every operator sequence left to right.
+    fn brute_force(target: u64, numbers: &[u64], operators: &[Operator]) -> bool {
+        let mut values = vec![numbers[0]];
+        for &n in &numbers[1..] {
+            values = values
+                .iter()
+                .flat_map(|&v| operators.iter().filter_map(move |op| op.apply(v, n)))
+                .collect();
+        }
+        values.contains(&target)
+    }
+
+    proptest! {
+        #[test]
+        fn test_day07_concat_matches_strings(x: u64, y: u64) {
+            let expected = format!("{}{}", x, y).parse::<u64>().ok();
+
+            prop_assert_eq!(concat(x, y), expected);
+        }
+
+        #[test]
+        fn test_day07_concat_small(x in 0u64..1_000_000, y in 0u64..1_000_000) {
+            let expected = format!("{}{}", x, y).parse::<u64>().unwrap();
+
+            prop_assert_eq!(concat(x, y), Some(expected));
+        }
+
+        #[test]
+        fn test_day07_undo_inverts_apply(x in 0u64..1_000_000, y in 0u64..1_000_000) {
+            for op in Operator::PART2 {
+                let result = op.apply(x, y).unwrap();
+
+                prop_assert!(op.undo(result, y).is_some_and(|operand| operand.matches(x)));
+            }
+        }
+
+        #[test]
+        fn test_day07_solve_matches_brute_force(
+            numbers in prop::collection::vec(0u64..20, 1..6),
+            target in 0u64..5000,
+        ) {
+            for operators in [&Operator::PART1[..], &Operator::PART2[..]] {
+                prop_assert_eq!(
+                    solve(Operand::Exactly(target), &numbers, operators),
+                    brute_force(target, &numbers, operators)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_day07_multiply_by_zero() {
+        let input = Day07::parse("0: 3 0\n1: 3 0 4\n0: 3 5 0\n").unwrap();
+        let [times_zero, unsolvable, any_prefix] = &input.equations[..] else {
+            panic!("expected three equations");
+        };
+
+        assert!(times_zero.solvable(&Operator::PART1));
+        assert!(!unsolvable.solvable(&Operator::PART1));
+        // Whatever `3` and `5` combine to, multiplying by zero gives zero.
+        let expressions: Vec<String> = any_prefix
+            .solutions(&Operator::PART1)
+            .iter()
+            .map(|s| any_prefix.expression(s))
+            .collect();
+        assert_eq!(expressions, vec!["3 + 5 * 0", "3 * 5 * 0"]);
+    }
+
+    #[test]
+    fn test_day07_stray_token() {
+        let err = Day07::parse("190: 10 19\n3267: 81 x40 27\n").err().unwrap();
+
+        assert_eq!((err.line, err.column), (2, 10));
+    }
+}
@@ -1,16 +1,193 @@
 pub mod days;
+pub mod output;
+pub mod scaffold;
 pub mod solution;
 pub mod utils;
 
-use solution::{print_table, Solution};
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
-fn main() {
-    let solutions = solution::all_solutions();
-    let mut all_results = Vec::new();
-    for Solution { input, func, .. } in solutions {
-        let results = (func)(input);
-        all_results.push(results);
+use clap::{Args, Parser, Subcommand};
+use output::{print_results, Format};
+use rayon::prelude::*;
+use solution::{RunOptions, Solution};
+
+#[derive(Parser)]
+#[command(about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run solutions and print a table of their results
+    Run(RunArgs),
+    /// Run each stage repeatedly and report timing statistics
+    Bench(BenchArgs),
+    /// Generate a new day module and input files from the template
+    New {
+        #[arg(value_parser = clap::value_parser!(u32).range(1..=25))]
+        day: u32,
+
+        /// Year of the event the day belongs to
+        #[arg(short, long, default_value_t = 2024,
+              value_parser = clap::value_parser!(u32).range(2015..=9999))]
+        year: u32,
+    },
+}
+
+#[derive(Args, Default)]
+struct RunArgs {
+    /// Only run days from this year's event
+    #[arg(short, long)]
+    year: Option<u32>,
+
+    /// Day or inclusive range of days to run, e.g. `6` or `3-7`
+    #[arg(short, long, value_parser = parse_days)]
+    day: Option<RangeInclusive<u32>>,
+
+    /// Only run the given part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Read puzzle input from this file instead of `input/<year>/<day>/input.txt`,
+    /// or from stdin if `-`. Requires a single day to be selected.
+    #[arg(short, long)]
+    input: Option<PathBuf>,
+
+    /// Output format for the results
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Run days concurrently. Results are still printed in day order, but
+    /// timings are wall-clock and affected by contention between days.
+    #[arg(long)]
+    parallel: bool,
+
+    /// Print each day's explanation of how its answers were reached, for
+    /// days that provide one. Not included in CSV output.
+    #[arg(long)]
+    explain: bool,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    run: RunArgs,
+
+    /// Number of timed runs of each stage
+    #[arg(short = 'n', long, default_value_t = 100,
+          value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// Number of untimed runs of each stage before timing starts
+    #[arg(short, long, default_value_t = 5)]
+    warmup: u64,
+}
+
+fn parse_days(s: &str) -> Result<RangeInclusive<u32>, String> {
+    let parse = |n: &str| {
+        n.trim()
+            .parse::<u32>()
+            .map_err(|e| format!("invalid day `{}`: {}", n, e))
+    };
+
+    let (start, end) = match s.split_once('-') {
+        Some((start, end)) => (parse(start)?, parse(end)?),
+        None => (parse(s)?, parse(s)?),
+    };
+
+    if start > end {
+        return Err(format!("empty day range `{}`", s));
     }
 
-    print_table(all_results);
+    Ok(start..=end)
+}
+
+fn run(args: RunArgs, options: RunOptions) -> ExitCode {
+    let solutions: Vec<Solution> = solution::all_solutions()
+        .into_iter()
+        .filter(|s| args.year.is_none_or(|y| y == s.puzzle.year))
+        .filter(|s| args.day.as_ref().is_none_or(|d| d.contains(&s.puzzle.day)))
+        .collect();
+
+    if solutions.is_empty() {
+        eprintln!("no solutions found for the selected days");
+        return ExitCode::FAILURE;
+    }
+
+    if args.input.is_some() && solutions.len() > 1 {
+        eprintln!("--input requires a single day to be selected with --year and --day");
+        return ExitCode::FAILURE;
+    }
+
+    let options = RunOptions {
+        part: args.part,
+        explain: args.explain,
+        ..options
+    };
+    let input = args.input.as_deref();
+    let all_results: Vec<_> = if args.parallel {
+        solutions
+            .par_iter()
+            .map(|solution| solution.run(input, &options))
+            .collect()
+    } else {
+        solutions
+            .iter()
+            .map(|solution| solution.run(input, &options))
+            .collect()
+    };
+
+    let failures = all_results.iter().filter(|r| r.has_failures()).count();
+    print_results(all_results, args.format);
+
+    if args.parallel {
+        eprintln!("note: days ran in parallel, so timings are wall-clock under contention");
+    }
+
+    if failures > 0 {
+        eprintln!(
+            "{} day(s) failed or did not match their recorded answers",
+            failures
+        );
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn new(year: u32, day: u32) -> ExitCode {
+    match scaffold::new_day(Path::new(env!("CARGO_MANIFEST_DIR")), year, day) {
+        Ok(created) => {
+            for path in created {
+                println!("wrote {}", path.display());
+            }
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("could not create {} day {}: {}", year, day, e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::Run(args)) => run(args, RunOptions::default()),
+        Some(Command::Bench(args)) => {
+            let options = RunOptions {
+                iterations: args.iterations as usize,
+                warmup: args.warmup as usize,
+                ..RunOptions::default()
+            };
+            run(args.run, options)
+        }
+        Some(Command::New { day, year }) => new(year, day),
+        None => run(RunArgs::default(), RunOptions::default()),
+    }
 }
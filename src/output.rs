@@ -0,0 +1,321 @@
+use crate::solution::{Answer, Check, Output, Results, Timing};
+use clap::ValueEnum;
+use prettytable::{row, Table};
+use std::fmt::Write;
+
+#[derive(Clone, Copy, Debug, Default, ValueEnum)]
+pub enum Format {
+    /// Human readable table
+    #[default]
+    Table,
+    Json,
+    Csv,
+    /// GitHub flavoured Markdown table
+    Markdown,
+}
+
+const COLUMNS: [&str; 9] = [
+    "year",
+    "day",
+    "title",
+    "status",
+    "part1_output",
+    "part1_check",
+    "part2_output",
+    "part2_check",
+    "samples",
+];
+
+const STAGES: [&str; 4] = ["parse", "part1", "part2", "total"];
+
+/// The statistics reported for each stage, as suffixes of its `<stage>_time`
+/// column names. The unsuffixed column is the median.
+const STATS: [&str; 5] = ["", "_min", "_max", "_mean", "_stddev"];
+
+/// Every column name: `COLUMNS`, then each stage's timing statistics.
+fn columns() -> Vec<String> {
+    let timings = STAGES.iter().flat_map(|stage| {
+        STATS
+            .iter()
+            .map(move |stat| format!("{}_time{}_ns", stage, stat))
+    });
+    COLUMNS
+        .iter()
+        .map(|c| c.to_string())
+        .chain(timings)
+        .collect()
+}
+
+/// Each stage's timing statistics in nanoseconds, in `columns()` order.
+fn timings(r: &Results) -> Vec<u128> {
+    let stats = |t: &Timing| [t.median, t.min, t.max, t.mean, t.stddev].map(|d| d.as_nanos());
+    [&r.parse_time, &r.part1_time, &r.part2_time, &r.total_time]
+        .into_iter()
+        .flat_map(stats)
+        .collect()
+}
+
+pub fn print_results(results: Vec<Results>, format: Format) {
+    match format {
+        Format::Table => print_table(results),
+        Format::Json => print!("{}", to_json(&results)),
+        Format::Csv => print!("{}", to_csv(&results)),
+        Format::Markdown => print!("{}", to_markdown(&results)),
+    }
+}
+
+/// The stars earned across `results`, and the number available.
+fn stars(results: &[Results]) -> (usize, usize) {
+    let earned = results.iter().map(|r| r.stars()).sum();
+    (earned, results.len() * 2)
+}
+
+pub fn print_table(results: Vec<Results>) {
+    let mut table = Table::new();
+
+    table.set_titles(row![
+        "Year",
+        "Day",
+        "Title",
+        "Status",
+        "Part1 Output",
+        "Part2 Output",
+        "Parse Time",
+        "Part1 Time",
+        "Part2 Time",
+        "Total Time"
+    ]);
+    for r in results.iter() {
+        table.add_row(r.as_row());
+    }
+
+    table.printstd();
+
+    let (earned, available) = stars(&results);
+    println!("Stars: {}/{}", earned, available);
+
+    for r in results.iter().filter(|r| !r.explanation.is_empty()) {
+        println!(
+            "\n{} day {}: {}",
+            r.puzzle.year, r.puzzle.day, r.puzzle.title
+        );
+        for line in &r.explanation {
+            println!("  {}", line);
+        }
+    }
+}
+
+/// The cells of a results row, in `columns()` order, with outputs left empty
+/// when not run and durations in nanoseconds.
+fn fields(r: &Results) -> Vec<String> {
+    let output = |o: &Output| match o {
+        Output::NotRun => String::new(),
+        o => o.to_string(),
+    };
+
+    let fields = [
+        r.puzzle.year.to_string(),
+        r.puzzle.day.to_string(),
+        r.puzzle.title.to_string(),
+        r.status.to_string(),
+        output(&r.part1_output),
+        r.part1_check.label().to_string(),
+        output(&r.part2_output),
+        r.part2_check.label().to_string(),
+        r.total_time.samples.to_string(),
+    ];
+    let timings = timings(r).into_iter().map(|ns| ns.to_string());
+    fields.into_iter().chain(timings).collect()
+}
+
+pub fn to_json(results: &[Results]) -> String {
+    let mut out = String::from("{\n  \"days\": [");
+
+    for (i, r) in results.iter().enumerate() {
+        let sep = if i == 0 { "" } else { "," };
+        let output = |part: &str, o: &Output| {
+            let (value, state) = match o {
+                Output::Solved(Answer::Text(s)) => (json_string(s), "solved"),
+                Output::Solved(answer) => (answer.to_string(), "solved"),
+                Output::Unsolved => ("null".to_string(), "unsolved"),
+                Output::NotRun => ("null".to_string(), "not run"),
+            };
+            format!(
+                "\"{}_output\": {}, \"{}_state\": \"{}\"",
+                part, value, part, state
+            )
+        };
+        let check = |part: &str, c: &Check| {
+            let expected = match c {
+                Check::Fail(expected) => json_string(expected),
+                _ => "null".to_string(),
+            };
+            format!(
+                "\"{}_check\": {}, \"{}_expected\": {}",
+                part,
+                json_string(c.label()),
+                part,
+                expected
+            )
+        };
+
+        let timings: Vec<String> = columns()[COLUMNS.len()..]
+            .iter()
+            .zip(timings(r))
+            .map(|(column, ns)| format!("\"{}\": {}", column, ns))
+            .collect();
+
+        let explanation = if r.explanation.is_empty() {
+            String::new()
+        } else {
+            let lines: Vec<String> = r.explanation.iter().map(|l| json_string(l)).collect();
+            format!(", \"explanation\": [{}]", lines.join(", "))
+        };
+
+        write!(
+            out,
+            "{}\n    {{\"year\": {}, \"day\": {}, \"title\": {}, \"status\": {}, {}, {}, {}, {}, \
+             \"samples\": {}, {}{}}}",
+            sep,
+            r.puzzle.year,
+            r.puzzle.day,
+            json_string(r.puzzle.title),
+            json_string(&r.status.to_string()),
+            output("part1", &r.part1_output),
+            check("part1", &r.part1_check),
+            output("part2", &r.part2_output),
+            check("part2", &r.part2_check),
+            r.total_time.samples,
+            timings.join(", "),
+            explanation,
+        )
+        .unwrap();
+    }
+
+    let (earned, available) = stars(results);
+    write!(
+        out,
+        "\n  ],\n  \"stars\": {},\n  \"available_stars\": {}\n}}\n",
+        earned, available
+    )
+    .unwrap();
+    out
+}
+
+pub fn to_csv(results: &[Results]) -> String {
+    let mut out = columns().join(",");
+    out.push('\n');
+
+    for r in results {
+        let row: Vec<String> = fields(r).iter().map(|f| csv_field(f)).collect();
+        out.push_str(&row.join(","));
+        out.push('\n');
+    }
+
+    out
+}
+
+pub fn to_markdown(results: &[Results]) -> String {
+    let columns = columns();
+    let mut out = format!("| {} |\n", columns.join(" | "));
+    out.push_str(&format!("|{}\n", "---|".repeat(columns.len())));
+
+    for r in results {
+        let row: Vec<String> = fields(r).iter().map(|f| markdown_cell(f)).collect();
+        out.push_str(&format!("| {} |\n", row.join(" | ")));
+    }
+
+    let (earned, available) = stars(results);
+    out.push_str(&format!("\n**Stars:** {}/{}\n", earned, available));
+
+    for r in results.iter().filter(|r| !r.explanation.is_empty()) {
+        out.push_str(&format!(
+            "\n### {} day {}: {}\n\n```\n{}\n```\n",
+            r.puzzle.year,
+            r.puzzle.day,
+            r.puzzle.title,
+            r.explanation.join("\n")
+        ));
+    }
+    out
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => write!(out, "\\u{:04x}", c as u32).unwrap(),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+// Escapes `s` for a Markdown table cell, which must stay on one line.
+fn markdown_cell(s: &str) -> String {
+    s.replace('|', "\\|")
+        .replace("\r\n", "<br>")
+        .replace(['\n', '\r'], "<br>")
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::solution::{Puzzle, Status};
+    use std::time::Duration;
+
+    #[test]
+    fn test_json_string_escapes() {
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+
+    #[test]
+    fn test_fields_match_columns() {
+        let puzzle = Puzzle {
+            year: 2024,
+            day: 1,
+            title: "Test",
+        };
+        let mut results = Results::failed(puzzle, Status::Ok);
+        results.part2_time = Timing::from_samples(&[1, 3, 8].map(Duration::from_nanos));
+
+        let columns = columns();
+        let fields = fields(&results);
+
+        assert_eq!(fields.len(), columns.len());
+        let field = |name: &str| &fields[columns.iter().position(|c| c == name).unwrap()];
+        assert_eq!(field("part2_time_ns"), "3");
+        assert_eq!(field("part2_time_min_ns"), "1");
+        assert_eq!(field("part2_time_max_ns"), "8");
+        assert_eq!(field("part2_time_mean_ns"), "4");
+    }
+
+    #[test]
+    fn test_markdown_cell_escapes() {
+        assert_eq!(markdown_cell("plain"), "plain");
+        assert_eq!(
+            markdown_cell("panicked: a | b\nat x\r\ny"),
+            "panicked: a \\| b<br>at x<br>y"
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quotes() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,\"b\""), "\"a,\"\"b\"\"\"");
+    }
+}
@@ -0,0 +1,147 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = include_str!("days/template.rs");
+
+/// Creates the module and input files for `day` of `year` from
+/// `days/template.rs` and declares the module in `days/yYYYY/mod.rs`, and the
+/// year in `days/mod.rs` if it is new. The build script then registers the day,
+/// so it runs on the next build.
+///
+/// Returns the paths that were created or updated.
+pub fn new_day(root: &Path, year: u32, day: u32) -> io::Result<Vec<PathBuf>> {
+    let name = format!("day{:02}", day);
+    let mut created = Vec::new();
+
+    let module_dir = root.join("src/days").join(format!("y{}", year));
+    fs::create_dir_all(&module_dir)?;
+    let module = module_dir.join(format!("{}.rs", name));
+    if module.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("{} already exists", module.display()),
+        ));
+    }
+    let source = TEMPLATE
+        .replace("0000/day00", &format!("{}/{}", year, name))
+        .replace("day00", &name)
+        .replace("Day00", &format!("Day{:02}", day))
+        .replace(
+            "const YEAR: u32 = 0;",
+            &format!("const YEAR: u32 = {};", year),
+        )
+        .replace("const DAY: u32 = 0;", &format!("const DAY: u32 = {};", day));
+    fs::write(&module, source)?;
+    created.push(module);
+
+    let year_mod = module_dir.join("mod.rs");
+    if !year_mod.exists() {
+        let days_mod = root.join("src/days/mod.rs");
+        declare_module(&days_mod, &format!("y{}", year))?;
+        created.push(days_mod);
+    }
+    declare_module(&year_mod, &name)?;
+    created.push(year_mod);
+
+    let input_dir = root.join("input").join(year.to_string()).join(&name);
+    fs::create_dir_all(&input_dir)?;
+    for file in ["input.txt", "test.txt"] {
+        let path = input_dir.join(file);
+        if !path.exists() {
+            fs::write(&path, "")?;
+            created.push(path);
+        }
+    }
+
+    Ok(created)
+}
+
+/// Adds `pub mod name;` to the module file `path`, creating it if needed.
+/// Declarations are kept sorted, which for `yYYYY` and `dayNN` is numeric order.
+fn declare_module(path: &Path, name: &str) -> io::Result<()> {
+    let source = match fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(e),
+    };
+
+    let line = format!("pub mod {};", name);
+    let mut lines: Vec<&str> = source.lines().collect();
+    if !lines.contains(&line.as_str()) {
+        let at = lines.partition_point(|l| *l < line.as_str());
+        lines.insert(at, &line);
+    }
+
+    let mut source = lines.join("\n");
+    source.push('\n');
+    fs::write(path, source)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        let input_dir = root.join("input/2023/day08");
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("input.txt"), "LR\n").unwrap();
+
+        let days_mod = root.join("src/days/mod.rs");
+        fs::create_dir_all(days_mod.parent().unwrap()).unwrap();
+        fs::write(&days_mod, "pub mod y2024;\n").unwrap();
+
+        let created = new_day(&root, 2023, 8).unwrap();
+
+        let module = root.join("src/days/y2023/day08.rs");
+        let year_mod = root.join("src/days/y2023/mod.rs");
+        assert_eq!(
+            created,
+            vec![
+                module.clone(),
+                days_mod.clone(),
+                year_mod.clone(),
+                input_dir.join("test.txt")
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(&days_mod).unwrap(),
+            "pub mod y2023;\npub mod y2024;\n"
+        );
+        assert_eq!(fs::read_to_string(&year_mod).unwrap(), "pub mod day08;\n");
+
+        let source = fs::read_to_string(&module).unwrap();
+        assert!(source.contains("pub struct Day08;"));
+        assert!(source.contains("const YEAR: u32 = 2023;"));
+        assert!(source.contains("const DAY: u32 = 8;"));
+        assert!(source.contains("\"../../../input/2023/day08/test.txt\""));
+        assert!(source.contains("fn test_day08_part1()"));
+        for placeholder in ["Day00", "day00", "0000", "= 0;"] {
+            assert!(!source.contains(placeholder), "{}", source);
+        }
+
+        // An existing input is kept, and an existing module is never replaced.
+        assert_eq!(
+            fs::read_to_string(input_dir.join("input.txt")).unwrap(),
+            "LR\n"
+        );
+        let err = new_day(&root, 2023, 8).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        // A later day of the same year joins the existing declarations in order.
+        new_day(&root, 2023, 3).unwrap();
+        assert_eq!(
+            fs::read_to_string(&year_mod).unwrap(),
+            "pub mod day03;\npub mod day08;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(&days_mod).unwrap(),
+            "pub mod y2023;\npub mod y2024;\n"
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
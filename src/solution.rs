@@ -1,108 +1,679 @@
 use crate::days;
-use prettytable::{row, Row, Table};
+use crate::utils::parse::ParseError;
+use prettytable::{row, Row};
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Once;
 use std::time::{Duration, Instant};
 
+/// Whether a day ran to completion, or why it could not.
+#[derive(Debug)]
+pub enum Status {
+    Ok,
+    InputMissing(String),
+    ParseError(ParseError),
+    Panicked(String),
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::InputMissing(reason) => write!(f, "input missing: {}", reason),
+            Status::ParseError(e) => write!(f, "parse error at {}", e),
+            Status::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// A puzzle answer. Most are numbers, but some puzzles ask for text such as a
+/// comma separated list or a code spelled out in letters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+macro_rules! answer_from {
+    ($variant:ident, $target:ty, $($t:ty),+) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $target)
+                }
+            }
+        )+
+    };
+}
+
+answer_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+answer_from!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+/// The output of a single part of a day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Output {
+    Solved(Answer),
+    /// The part ran but has no answer, e.g. it is not implemented yet.
+    Unsolved,
+    /// The part was skipped or the day failed before reaching it.
+    NotRun,
+}
+
+impl Output {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Output::Solved(_))
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Output {
+    fn from(output: Option<T>) -> Self {
+        match output {
+            Some(answer) => Output::Solved(answer.into()),
+            None => Output::Unsolved,
+        }
+    }
+}
+
+impl fmt::Display for Output {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Output::Solved(answer) => write!(f, "{}", answer),
+            Output::Unsolved => write!(f, "unsolved"),
+            Output::NotRun => write!(f, "-"),
+        }
+    }
+}
+
+/// The outcome of comparing a part's output against its recorded answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Check {
+    Unknown,
+    Pass,
+    Fail(String),
+}
+
+impl Check {
+    fn new(output: &Output, expected: Option<&str>) -> Check {
+        match (output, expected) {
+            (_, None) => Check::Unknown,
+            (Output::Solved(answer), Some(expected)) if answer.to_string() == expected => {
+                Check::Pass
+            }
+            (_, Some(expected)) => Check::Fail(expected.to_string()),
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Check::Unknown => "unknown",
+            Check::Pass => "pass",
+            Check::Fail(_) => "fail",
+        }
+    }
+}
+
+/// Expected outputs recorded in a day's `answers.txt`, one `partN: <answer>`
+/// line per known part.
+#[derive(Debug, Default)]
+pub struct Answers {
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(data: &str) -> Answers {
+        let mut answers = Answers::default();
+        for line in data.lines() {
+            let Some((part, answer)) = line.split_once(':') else {
+                continue;
+            };
+
+            let answer = Some(answer.trim().to_string()).filter(|a| !a.is_empty());
+            match part.trim() {
+                "part1" => answers.part1 = answer,
+                "part2" => answers.part2 = answer,
+                _ => {}
+            }
+        }
+
+        answers
+    }
+
+    /// Reads the answers file next to `input_path`, if there is one.
+    pub fn load(input_path: &Path) -> Answers {
+        fs::read_to_string(input_path.with_file_name("answers.txt"))
+            .map(|data| Answers::parse(&data))
+            .unwrap_or_default()
+    }
+}
+
+/// Summary statistics over the timed runs of a single stage.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Timing {
+    pub samples: usize,
+    pub min: Duration,
+    pub max: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Timing {
+    pub fn from_samples(samples: &[Duration]) -> Timing {
+        if samples.is_empty() {
+            return Timing::default();
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let n = sorted.len();
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+
+        let mean = sorted.iter().map(|d| d.as_secs_f64()).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        Timing {
+            samples: n,
+            min: sorted[0],
+            max: sorted[n - 1],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+
+    /// Statistics over the total time of each iteration across `stages`,
+    /// whose samples line up by iteration. Stages that were not run have no
+    /// samples and add nothing.
+    pub fn from_stages(stages: &[&[Duration]]) -> Timing {
+        let Some((first, rest)) = stages.split_first() else {
+            return Timing::default();
+        };
+        let mut totals = first.to_vec();
+        for stage in rest {
+            for (total, &sample) in totals.iter_mut().zip(*stage) {
+                *total += sample;
+            }
+        }
+        Timing::from_samples(&totals)
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.samples <= 1 {
+            return write!(f, "{:?}", self.median);
+        }
+
+        write!(
+            f,
+            "min    {:?}\nmedian {:?}\nmax    {:?}\nmean   {:?}\nstddev {:?}",
+            self.min, self.median, self.max, self.mean, self.stddev
+        )
+    }
+}
+
+/// A day's puzzle solution, driven generically by the runner and tests.
+pub trait Solver {
+    type Input;
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    /// The puzzle title, e.g. "Guard Gallivant".
+    const TITLE: &'static str;
+    const YEAR: u32;
+    const DAY: u32;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Option<Self::Part1>;
+    fn part2(input: &Self::Input) -> Option<Self::Part2>;
+
+    /// Lines describing how the answers were reached, printed by `--explain`.
+    /// Days that have nothing to add leave this empty.
+    #[allow(unused_variables)]
+    fn explain(input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+/// Identifies a puzzle across all years.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Puzzle {
+    pub year: u32,
+    pub day: u32,
+    pub title: &'static str,
+}
+
+impl Puzzle {
+    pub fn of<S: Solver>() -> Puzzle {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            title: S::TITLE,
+        }
+    }
+}
+
 pub struct Results {
-    pub day: &'static str,
-    pub part1_output: Option<u64>,
-    pub part2_output: Option<u64>,
-    pub parse_time: Duration,
-    pub part1_time: Duration,
-    pub part2_time: Duration,
-    pub total_time: Duration,
+    pub puzzle: Puzzle,
+    pub status: Status,
+    pub part1_output: Output,
+    pub part2_output: Output,
+    pub part1_check: Check,
+    pub part2_check: Check,
+    pub parse_time: Timing,
+    pub part1_time: Timing,
+    pub part2_time: Timing,
+    pub total_time: Timing,
+    pub explanation: Vec<String>,
 }
 
 impl Results {
+    /// Results for a day that could not be run at all.
+    pub fn failed(puzzle: Puzzle, status: Status) -> Results {
+        Results {
+            puzzle,
+            status,
+            part1_output: Output::NotRun,
+            part2_output: Output::NotRun,
+            part1_check: Check::Unknown,
+            part2_check: Check::Unknown,
+            parse_time: Timing::default(),
+            part1_time: Timing::default(),
+            part2_time: Timing::default(),
+            total_time: Timing::default(),
+            explanation: Vec::new(),
+        }
+    }
+
+    /// Compares the outputs against `answers`, leaving parts that were not run
+    /// unchecked. A day that failed to parse or panicked fails every recorded
+    /// answer it was asked for; only a missing input is left unchecked.
+    pub fn check(&mut self, answers: &Answers, options: &RunOptions) {
+        if matches!(self.status, Status::InputMissing(_)) {
+            return;
+        }
+
+        if options.runs_part(1) {
+            self.part1_check = Check::new(&self.part1_output, answers.part1.as_deref());
+        }
+        if options.runs_part(2) {
+            self.part2_check = Check::new(&self.part2_output, answers.part2.as_deref());
+        }
+    }
+
+    /// The number of parts with an answer that did not fail its check.
+    pub fn stars(&self) -> usize {
+        [
+            (&self.part1_output, &self.part1_check),
+            (&self.part2_output, &self.part2_check),
+        ]
+        .iter()
+        .filter(|(output, check)| output.is_solved() && !matches!(check, Check::Fail(_)))
+        .count()
+    }
+
+    pub fn has_failures(&self) -> bool {
+        matches!(self.part1_check, Check::Fail(_)) || matches!(self.part2_check, Check::Fail(_))
+    }
+
     pub fn as_row(&self) -> Row {
+        let output = |output: &Output, check: &Check| match check {
+            Check::Unknown => output.to_string(),
+            Check::Pass => format!("{} (pass)", output),
+            Check::Fail(expected) => format!("{} (fail, expected {})", output, expected),
+        };
+
         row![
-            self.day,
-            self.part1_output.unwrap_or(0),
-            self.part2_output.unwrap_or(0),
-            format!("{:?}", self.parse_time),
-            format!("{:?}", self.part1_time),
-            format!("{:?}", self.part2_time),
-            format!("{:?}", self.total_time),
+            self.puzzle.year,
+            self.puzzle.day,
+            self.puzzle.title,
+            self.status,
+            output(&self.part1_output, &self.part1_check),
+            output(&self.part2_output, &self.part2_check),
+            self.parse_time,
+            self.part1_time,
+            self.part2_time,
+            self.total_time,
         ]
     }
 }
 
-pub fn print_table(results: Vec<Results>) {
-    let mut table = Table::new();
+/// Options controlling which parts of a solution are run, and how many times.
+pub struct RunOptions {
+    pub part: Option<u8>,
+    pub iterations: usize,
+    pub warmup: usize,
+    /// Whether to collect each day's explanation of its answers.
+    pub explain: bool,
+}
 
-    table.set_titles(row![
-        "Day",
-        "Part1 Output",
-        "Part2 Output",
-        "Parse Time",
-        "Part1 Time",
-        "Part2 Time",
-        "Total Time"
-    ]);
-    for r in results {
-        table.add_row(r.as_row());
+impl Default for RunOptions {
+    fn default() -> Self {
+        RunOptions {
+            part: None,
+            iterations: 1,
+            warmup: 0,
+            explain: false,
+        }
     }
+}
+
+impl RunOptions {
+    pub fn runs_part(&self, part: u8) -> bool {
+        self.part.is_none_or(|p| p == part)
+    }
+
+    /// Runs `f` `warmup` times untimed, then `iterations` times timed, returning
+    /// the output of the last run and the time each run took.
+    fn measure<T>(&self, mut f: impl FnMut() -> T) -> (T, Vec<Duration>) {
+        for _ in 0..self.warmup {
+            black_box(f());
+        }
+
+        let mut samples = Vec::with_capacity(self.iterations);
+        let mut output = None;
+        for _ in 0..self.iterations.max(1) {
+            let start = Instant::now();
+            output = Some(black_box(f()));
+            samples.push(start.elapsed());
+        }
+
+        (output.unwrap(), samples)
+    }
+}
 
-    table.printstd();
+/// Runs each stage of `S` according to `options`.
+pub fn run_solver<S: Solver>(data: &str, options: &RunOptions) -> Results {
+    let (input, parse_samples) = options.measure(|| S::parse(data));
+    let parse_time = Timing::from_samples(&parse_samples);
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => {
+            let mut results = Results::failed(Puzzle::of::<S>(), Status::ParseError(e));
+            results.parse_time = parse_time;
+            results.total_time = parse_time;
+            return results;
+        }
+    };
+
+    let (part1_output, part1_samples) = if options.runs_part(1) {
+        let (output, samples) = options.measure(|| S::part1(&input));
+        (Output::from(output), samples)
+    } else {
+        (Output::NotRun, Vec::new())
+    };
+
+    let (part2_output, part2_samples) = if options.runs_part(2) {
+        let (output, samples) = options.measure(|| S::part2(&input));
+        (Output::from(output), samples)
+    } else {
+        (Output::NotRun, Vec::new())
+    };
+
+    Results {
+        puzzle: Puzzle::of::<S>(),
+        status: Status::Ok,
+        part1_output,
+        part2_output,
+        part1_check: Check::Unknown,
+        part2_check: Check::Unknown,
+        parse_time,
+        part1_time: Timing::from_samples(&part1_samples),
+        part2_time: Timing::from_samples(&part2_samples),
+        total_time: Timing::from_stages(&[&parse_samples, &part1_samples, &part2_samples]),
+        explanation: if options.explain {
+            S::explain(&input)
+        } else {
+            Vec::new()
+        },
+    }
 }
 
 pub struct Solution {
-    pub day: &'static str,
-    pub input: &'static str,
-    pub func: fn(&str) -> Results,
+    pub puzzle: Puzzle,
+    pub input_path: PathBuf,
+    pub func: fn(&str, &RunOptions) -> Results,
 }
 
-#[macro_export]
-macro_rules! solution {
-    ($day:tt) => {
+impl Solution {
+    pub fn new<S: Solver>() -> Solution {
         Solution {
-            day: stringify!($day),
-            input: include_str!(concat!("../input/", stringify!($day), "/input.txt")),
-            func: |data: &str| {
-                let mut t = [Instant::now(); 4];
-                t[0] = Instant::now();
-                let input = days::$day::parse_input(data);
-                t[1] = Instant::now();
-                let part1_output = days::$day::part1(&input);
-                t[2] = Instant::now();
-                let part2_output = days::$day::part2(&input);
-                t[3] = Instant::now();
-
-                Results {
-                    day: stringify!($day),
-                    part1_output,
-                    part2_output,
-                    parse_time: t[1] - t[0],
-                    part1_time: t[2] - t[1],
-                    part2_time: t[3] - t[2],
-                    total_time: t[3] - t[0],
+            puzzle: Puzzle::of::<S>(),
+            input_path: Path::new(env!("CARGO_MANIFEST_DIR")).join(format!(
+                "input/{}/day{:02}/input.txt",
+                S::YEAR,
+                S::DAY
+            )),
+            func: run_solver::<S>,
+        }
+    }
+
+    /// Reads the puzzle input and runs the solution against it.
+    ///
+    /// `input` overrides the default input path; a path of `-` reads from stdin.
+    /// Outputs are only checked against recorded answers for the default input.
+    pub fn run(&self, input: Option<&Path>, options: &RunOptions) -> Results {
+        let path = input.unwrap_or(&self.input_path);
+        let data = if path == Path::new("-") {
+            io::read_to_string(io::stdin())
+        } else {
+            fs::read_to_string(path)
+        };
+
+        match data {
+            Ok(data) => {
+                let mut results =
+                    catch_panic(|| (self.func)(&data, options)).unwrap_or_else(|message| {
+                        Results::failed(self.puzzle, Status::Panicked(message))
+                    });
+                if input.is_none() {
+                    results.check(&Answers::load(path), options);
                 }
-            },
+                results
+            }
+            Err(e) => Results::failed(
+                self.puzzle,
+                Status::InputMissing(format!("{} ({})", path.display(), e)),
+            ),
         }
-    };
+    }
 }
 
+thread_local! {
+    static CAPTURING_PANIC: Cell<bool> = const { Cell::new(false) };
+    static PANIC_MESSAGE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// Runs `f`, returning the message and location of any panic instead of
+/// unwinding further. Panics caught here are not printed to stderr.
+pub fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CAPTURING_PANIC.get() {
+                return default_hook(info);
+            }
+
+            let message = info.payload_as_str().unwrap_or("Box<dyn Any>");
+            let message = match info.location() {
+                Some(location) => format!("{} at {}", message, location),
+                None => message.to_string(),
+            };
+            PANIC_MESSAGE.set(Some(message));
+        }));
+    });
+
+    CAPTURING_PANIC.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CAPTURING_PANIC.set(false);
+
+    result.map_err(|_| {
+        PANIC_MESSAGE
+            .take()
+            .unwrap_or_else(|| "unknown panic".to_string())
+    })
+}
+
+/// Every day in `src/days`, in year and day order. The list is generated by `build.rs`.
 pub fn all_solutions() -> Vec<Solution> {
-    vec![
-        solution!(day01),
-        solution!(day02),
-        solution!(day03),
-        // solution!(day04),
-        solution!(day05),
-        solution!(day06),
-        solution!(day07),
-        // solution!(day08),
-        // solution!(day09),
-        // solution!(day10),
-        // solution!(day11),
-        // solution!(day12),
-        // solution!(day13),
-        // solution!(day14),
-        // solution!(day15),
-        // solution!(day18),
-        // solution!(day19),
-        // solution!(day20),
-        // solution!(day22),
-        // solution!(day24),
-        // solution!(day25),
-    ]
+    include!(concat!(env!("OUT_DIR"), "/solutions.rs"))
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_answers_check() {
+        let answers = Answers::parse("part1: 143\npart2:\n");
+
+        assert_eq!(answers.part1.as_deref(), Some("143"));
+        assert_eq!(answers.part2, None);
+        assert_eq!(
+            Check::new(
+                &Output::Solved(Answer::from(143u64)),
+                answers.part1.as_deref()
+            ),
+            Check::Pass
+        );
+        assert_eq!(
+            Check::new(&Output::Unsolved, answers.part1.as_deref()),
+            Check::Fail("143".to_string())
+        );
+        assert_eq!(
+            Check::new(
+                &Output::Solved(Answer::from("123")),
+                answers.part2.as_deref()
+            ),
+            Check::Unknown
+        );
+    }
+
+    // Writes `input` and `answers` into a fresh directory, returning the
+    // input path.
+    fn write_day(name: &str, input: &str, answers: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-{}-{}", name, std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("answers.txt"), answers).unwrap();
+        let path = dir.join("input.txt");
+        fs::write(&path, input).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_panic_fails_recorded_answers() {
+        let solution = Solution {
+            puzzle: Puzzle {
+                year: 0,
+                day: 0,
+                title: "Panics",
+            },
+            input_path: write_day("panic", "", "part1: 1\n"),
+            func: |_, _| panic!("bad path"),
+        };
+
+        let results = solution.run(None, &RunOptions::default());
+
+        assert!(matches!(results.status, Status::Panicked(_)));
+        assert_eq!(results.part1_check, Check::Fail("1".to_string()));
+        assert_eq!(results.part2_check, Check::Unknown);
+        assert!(results.has_failures());
+    }
+
+    #[test]
+    fn test_parse_error_fails_recorded_answers() {
+        let solution = Solution {
+            input_path: write_day("parse-error", "1 2\n3\n", "part1: 1\npart2: 2\n"),
+            ..Solution::new::<days::y2024::day01::Day01>()
+        };
+
+        let results = solution.run(None, &RunOptions::default());
+
+        assert!(matches!(results.status, Status::ParseError(_)));
+        assert_eq!(results.part1_check, Check::Fail("1".to_string()));
+        assert_eq!(results.part2_check, Check::Fail("2".to_string()));
+        assert!(results.has_failures());
+    }
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(42u64).to_string(), "42");
+        assert_eq!(Answer::from(-7i32).to_string(), "-7");
+        assert_eq!(Answer::from("6,0,9").to_string(), "6,0,9");
+    }
+
+    #[test]
+    fn test_catch_panic() {
+        assert_eq!(catch_panic(|| 1), Ok(1));
+
+        let err = catch_panic(|| -> u32 { panic!("bad path") }).unwrap_err();
+        assert!(err.starts_with("bad path at src/solution.rs:"), "{}", err);
+    }
+
+    #[test]
+    fn test_timing_from_samples() {
+        let samples: Vec<Duration> = [4, 1, 3, 2].map(Duration::from_micros).to_vec();
+
+        let timing = Timing::from_samples(&samples);
+
+        assert_eq!(timing.samples, 4);
+        assert_eq!(timing.min, Duration::from_micros(1));
+        assert_eq!(timing.max, Duration::from_micros(4));
+        assert_eq!(timing.median, Duration::from_nanos(2500));
+        assert_eq!(timing.mean, Duration::from_nanos(2500));
+    }
+
+    #[test]
+    fn test_timing_from_stages() {
+        // The stage medians sum to 4 and the minimums to 2, but the
+        // iterations took 3, 3 and 6 in total.
+        let parse = [1, 2, 3].map(Duration::from_micros);
+        let part1 = [2, 1, 3].map(Duration::from_micros);
+
+        let timing = Timing::from_stages(&[&parse, &part1, &[]]);
+
+        assert_eq!(timing.samples, 3);
+        assert_eq!(timing.min, Duration::from_micros(3));
+        assert_eq!(timing.median, Duration::from_micros(3));
+        assert_eq!(timing.max, Duration::from_micros(6));
+    }
 }
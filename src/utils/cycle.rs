@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The eventual cycle of a deterministic sequence of states `x0, x1, ...`
+/// where each state is computed from the last.
+///
+/// States `start..start + length` repeat forever, so `x(start + length)` is
+/// `x(start)` again.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: u64,
+    pub length: u64,
+}
+
+impl Cycle {
+    /// The earliest step with the same state as step `n`.
+    pub fn reduce(&self, n: u64) -> u64 {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+fn advance<S>(mut state: S, step: &mut impl FnMut(&S) -> S, n: u64) -> S {
+    for _ in 0..n {
+        state = step(&state);
+    }
+    state
+}
+
+/// Floyd's tortoise and hare. Keeps only two states, but calls `step` about
+/// three times as often as `brent`.
+///
+/// Never returns if the sequence does not cycle.
+pub fn floyd<S: PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut tortoise = step(&initial);
+    let mut hare = step(&tortoise);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = advance(hare, &mut step, 2);
+    }
+
+    // The hare is now a multiple of the cycle length ahead, so walking both
+    // in lockstep from the start and the meeting point meets at the cycle.
+    let mut start = 0;
+    tortoise = initial;
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    let mut length = 1;
+    hare = step(&tortoise);
+    while tortoise != hare {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Brent's algorithm. Keeps only two states and finds the cycle length
+/// before its start.
+///
+/// Never returns if the sequence does not cycle.
+pub fn brent<S: Clone + PartialEq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle {
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(&initial);
+    while tortoise != hare {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = advance(initial, &mut step, length);
+    while tortoise != hare {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle { start, length }
+}
+
+/// Finds the cycle by remembering every state seen. Calls `step` the fewest
+/// times, at the cost of storing each state.
+///
+/// Never returns if the sequence does not cycle.
+pub fn find_cycle<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S) -> Cycle {
+    detect(initial, step, u64::MAX).1.unwrap()
+}
+
+/// Steps through states until either step `limit` is reached or a state
+/// repeats, returning every state seen in order and the cycle if one was found.
+fn detect<S: Clone + Eq + Hash>(
+    initial: S,
+    mut step: impl FnMut(&S) -> S,
+    limit: u64,
+) -> (Vec<S>, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut history = Vec::new();
+    let mut state = initial;
+
+    for n in 0..=limit {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = Cycle {
+                start,
+                length: n - start,
+            };
+            return (history, Some(cycle));
+        }
+        seen.insert(state.clone(), n);
+        let next = step(&state);
+        history.push(state);
+        state = next;
+    }
+
+    (history, None)
+}
+
+/// The state after `n` steps from `initial`, skipping ahead once the states
+/// start to repeat, so `n` can be far larger than could be simulated.
+pub fn nth_state<S: Clone + Eq + Hash>(initial: S, step: impl FnMut(&S) -> S, n: u64) -> S {
+    let (mut history, cycle) = detect(initial, step, n);
+    let index = cycle.map_or(n, |cycle| cycle.reduce(n));
+    history.swap_remove(index as usize)
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 6, 7, 3, 4, ... enters a cycle of length 5 at step 3.
+    fn step(n: &u32) -> u32 {
+        if *n == 7 {
+            3
+        } else {
+            n + 1
+        }
+    }
+
+    const TEST_CYCLE: Cycle = Cycle {
+        start: 3,
+        length: 5,
+    };
+
+    #[test]
+    fn test_detectors_agree() {
+        assert_eq!(floyd(0, step), TEST_CYCLE);
+        assert_eq!(brent(0, step), TEST_CYCLE);
+        assert_eq!(find_cycle(0, step), TEST_CYCLE);
+
+        // A fixed point is a cycle of length 1.
+        let fixed = Cycle {
+            start: 0,
+            length: 1,
+        };
+        assert_eq!(floyd(7, |_| 7), fixed);
+        assert_eq!(brent(7, |_| 7), fixed);
+    }
+
+    #[test]
+    fn test_nth_state() {
+        assert_eq!(nth_state(0, step, 2), 2);
+        assert_eq!(nth_state(0, step, 8), 3);
+        // Step 10^12 is 999_999_999_997 = 2 (mod 5) steps into the cycle.
+        assert_eq!(nth_state(0, step, 1_000_000_000_000), 5);
+        assert_eq!(TEST_CYCLE.reduce(13), 3);
+    }
+}
@@ -0,0 +1,325 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+/// The result of exploring a graph outwards from one or more start nodes.
+///
+/// Every reached node has its shortest distance and the set of predecessors
+/// it can be reached from along a shortest path, so both a single path and
+/// every tied shortest path can be reconstructed.
+#[derive(Clone, Debug)]
+pub struct Search<N> {
+    dist: HashMap<N, u64>,
+    prev: HashMap<N, Vec<N>>,
+    // Nodes whose distance is final. A zero-cost edge can tie with the
+    // distance of a settled node, but taking it as a predecessor could close a
+    // loop in `prev`.
+    settled: HashSet<N>,
+}
+
+impl<N: Clone + Eq + Hash> Search<N> {
+    fn new() -> Search<N> {
+        Search {
+            dist: HashMap::new(),
+            prev: HashMap::new(),
+            settled: HashSet::new(),
+        }
+    }
+
+    /// Records that `node` can be reached from `from` at cost `dist`,
+    /// returning true if that improved on the best known distance.
+    ///
+    /// A tie adds `from` as another predecessor, unless `node` is a start node
+    /// or already settled, so predecessors never form a cycle.
+    fn relax(&mut self, node: &N, from: &N, dist: u64) -> bool {
+        match self.dist.get(node) {
+            Some(&best) if dist > best => false,
+            Some(&best) if dist == best => {
+                let prev = self.prev.get_mut(node).unwrap();
+                if !prev.is_empty() && !self.settled.contains(node) {
+                    prev.push(from.clone());
+                }
+                false
+            }
+            _ => {
+                self.dist.insert(node.clone(), dist);
+                self.prev.insert(node.clone(), vec![from.clone()]);
+                true
+            }
+        }
+    }
+
+    /// The shortest distance to `node`, or `None` if it was not reached.
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.dist.get(node).copied()
+    }
+
+    /// Every reached node with its shortest distance.
+    pub fn distances(&self) -> &HashMap<N, u64> {
+        &self.dist
+    }
+
+    /// The nodes `node` is entered from along its shortest paths. Empty for
+    /// start nodes and nodes that were not reached.
+    pub fn predecessors(&self, node: &N) -> &[N] {
+        self.prev.get(node).map_or(&[], |p| p.as_slice())
+    }
+
+    /// One shortest path from a start node to `node`, inclusive of both.
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        self.distance(node)?;
+        let mut path = vec![node.clone()];
+        while let Some(prev) = self.predecessors(path.last().unwrap()).first() {
+            path.push(prev.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Every node lying on some shortest path to `node`, including `node`.
+    pub fn nodes_on_paths_to(&self, node: &N) -> HashSet<N> {
+        let mut seen = HashSet::new();
+        if self.distance(node).is_none() {
+            return seen;
+        }
+
+        let mut stack = vec![node.clone()];
+        while let Some(n) = stack.pop() {
+            if seen.insert(n.clone()) {
+                stack.extend(self.predecessors(&n).iter().cloned());
+            }
+        }
+        seen
+    }
+
+    /// Every distinct shortest path from a start node to `node`. The number
+    /// of paths can grow exponentially, so prefer `nodes_on_paths_to` when
+    /// only the nodes matter.
+    pub fn all_paths_to(&self, node: &N) -> Vec<Vec<N>> {
+        if self.distance(node).is_none() {
+            return Vec::new();
+        }
+
+        let mut paths = Vec::new();
+        let mut stack = vec![vec![node.clone()]];
+        while let Some(path) = stack.pop() {
+            let prev = self.predecessors(path.last().unwrap());
+            if prev.is_empty() {
+                let mut path = path;
+                path.reverse();
+                paths.push(path);
+                continue;
+            }
+            for p in prev {
+                let mut next = path.clone();
+                next.push(p.clone());
+                stack.push(next);
+            }
+        }
+        paths
+    }
+}
+
+fn start_search<N: Clone + Eq + Hash>(starts: impl IntoIterator<Item = N>) -> (Search<N>, Vec<N>) {
+    let mut search = Search::new();
+    let mut frontier = Vec::new();
+    for start in starts {
+        if search.dist.insert(start.clone(), 0).is_none() {
+            search.prev.insert(start.clone(), Vec::new());
+            frontier.push(start);
+        }
+    }
+    (search, frontier)
+}
+
+/// Breadth-first search where every edge costs 1.
+///
+/// `neighbors` returns the nodes one step from the given node. The search
+/// explores everything reachable from `starts`.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let (mut search, frontier) = start_search(starts);
+    let mut queue: VecDeque<N> = frontier.into();
+
+    while let Some(node) = queue.pop_front() {
+        let dist = search.dist[&node] + 1;
+        for next in neighbors(&node) {
+            if search.relax(&next, &node, dist) {
+                queue.push_back(next);
+            }
+        }
+    }
+    search
+}
+
+/// Dijkstra's algorithm over non-negative edge costs.
+///
+/// `neighbors` returns the nodes reachable from the given node along with the
+/// cost of each edge. The search explores everything reachable from `starts`.
+pub fn dijkstra<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: impl FnMut(&N) -> I,
+) -> Search<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (mut search, frontier) = start_search(starts);
+    // The heap holds indices into `nodes` so that `N` need not be `Ord`.
+    let mut nodes = frontier;
+    let mut heap: BinaryHeap<_> = (0..nodes.len()).map(|i| Reverse((0, i))).collect();
+
+    while let Some(Reverse((dist, i))) = heap.pop() {
+        let node = nodes[i].clone();
+        if dist > search.dist[&node] || !search.settled.insert(node.clone()) {
+            continue;
+        }
+        for (next, cost) in neighbors(&node) {
+            if search.relax(&next, &node, dist + cost) {
+                heap.push(Reverse((dist + cost, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    search
+}
+
+/// A* search from `start` to the first node satisfying `is_goal`, returning
+/// its distance and one shortest path to it.
+///
+/// `heuristic` must never overestimate the remaining cost to a goal, or the
+/// path found may not be the shortest.
+pub fn astar<N, I>(
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (mut search, mut nodes) = start_search([start]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&nodes[0]), 0, 0))]);
+
+    while let Some(Reverse((_, dist, i))) = heap.pop() {
+        let node = nodes[i].clone();
+        if dist > search.dist[&node] || !search.settled.insert(node.clone()) {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some((dist, search.path_to(&node).unwrap()));
+        }
+        for (next, cost) in neighbors(&node) {
+            if search.relax(&next, &node, dist + cost) {
+                let estimate = dist + cost + heuristic(&next);
+                heap.push(Reverse((estimate, dist + cost, nodes.len())));
+                nodes.push(next);
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use crate::utils::grid::Grid;
+    use crate::utils::point::Point;
+
+    const TEST_MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open_neighbors(grid: &Grid<char>, pos: Point) -> Vec<Point> {
+        grid.neighbors4(pos)
+            .filter(|(_, &c)| c != '#')
+            .map(|(p, _)| p)
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs_on_grid() {
+        let grid = Grid::parse(TEST_MAZE).unwrap();
+        let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+
+        let search = bfs([start], |&p| open_neighbors(&grid, p));
+
+        assert_eq!(search.distance(&end), Some(5));
+        assert_eq!(search.distance(&Point::new(3, 1)), Some(4));
+        assert_eq!(search.distance(&Point::new(9, 9)), None);
+
+        let path = search.path_to(&end).unwrap();
+        assert_eq!((path[0], path[5]), (start, end));
+        assert_eq!(search.all_paths_to(&end).len(), 3);
+        assert_eq!(search.nodes_on_paths_to(&end).len(), 10);
+    }
+
+    #[test]
+    fn test_dijkstra_prefers_cheaper_path() {
+        // a -> b -> d costs 2, a -> c -> d costs 2, a -> d costs 5.
+        let edges = |n: &char| match n {
+            'a' => vec![('b', 1), ('c', 1), ('d', 5)],
+            'b' | 'c' => vec![('d', 1)],
+            _ => vec![],
+        };
+
+        let search = dijkstra(['a'], edges);
+
+        assert_eq!(search.distance(&'d'), Some(2));
+        let mut preds = search.predecessors(&'d').to_vec();
+        preds.sort();
+        assert_eq!(preds, vec!['b', 'c']);
+        assert_eq!(search.predecessors(&'a'), &[] as &[char]);
+    }
+
+    #[test]
+    fn test_dijkstra_zero_cost_cycles() {
+        // `s` and `a` are joined both ways at no cost, as are `b` and `c`.
+        let edges = |n: &char| match n {
+            's' => vec![('a', 0)],
+            'a' => vec![('s', 0), ('b', 1)],
+            'b' => vec![('c', 0)],
+            'c' => vec![('b', 0)],
+            _ => vec![],
+        };
+
+        let search = dijkstra(['s'], edges);
+
+        assert_eq!(search.distance(&'c'), Some(1));
+        assert_eq!(search.predecessors(&'s'), &[] as &[char]);
+        assert_eq!(search.predecessors(&'b'), &['a']);
+        assert_eq!(search.path_to(&'c'), Some(vec!['s', 'a', 'b', 'c']));
+        assert_eq!(search.all_paths_to(&'c'), vec![vec!['s', 'a', 'b', 'c']]);
+        assert_eq!(search.nodes_on_paths_to(&'c').len(), 4);
+    }
+
+    #[test]
+    fn test_astar_matches_bfs() {
+        let grid = Grid::parse(TEST_MAZE).unwrap();
+        let (start, end) = (grid.find(&'S').unwrap(), grid.find(&'E').unwrap());
+
+        let (dist, path) = astar(
+            start,
+            |&p| p == end,
+            |&p| open_neighbors(&grid, p).into_iter().map(|n| (n, 1)),
+            |&p| p.manhattan(end) as u64,
+        )
+        .unwrap();
+
+        assert_eq!(dist, 5);
+        assert_eq!(path.len(), 6);
+        assert!(path.windows(2).all(|w| w[0].manhattan(w[1]) == 1));
+
+        let blocked = astar(start, |_| false, |_: &Point| Vec::new(), |_| 0);
+        assert_eq!(blocked, None);
+    }
+}
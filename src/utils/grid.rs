@@ -0,0 +1,336 @@
+use crate::utils::parse::ParseError;
+use crate::utils::point::{Direction, Point};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// A rectangular grid stored row-major in a single `Vec`.
+///
+/// Positions are `Point`s (or `(x, y)` tuples) with `x` the column and `y` the
+/// row, counted from the top left. They are signed so that stepping off the
+/// edge can be expressed directly; lookups outside the grid return `None`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from row-major `cells`. Panics if there are not exactly
+    /// `width * height` of them.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one cell per character, one row per line. All rows must be the
+    /// same width.
+    pub fn parse_with(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, String>,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::with_capacity(input.len());
+
+        for line in input.lines() {
+            let start = cells.len();
+            for (i, c) in line.char_indices() {
+                let cell = f(c).map_err(|e| ParseError::at(input, &line[i..], e))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - start;
+            match width {
+                None => width = Some(row_width),
+                Some(w) if w != row_width => {
+                    return Err(ParseError::at(
+                        input,
+                        line,
+                        format!("expected a row of width {}, found {}", w, row_width),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid::from_vec(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: impl Into<Point>) -> bool {
+        let Point { x, y } = pos.into();
+        x >= 0 && y >= 0 && (x as usize) < self.width && (y as usize) < self.height
+    }
+
+    fn offset(&self, pos: Point) -> Option<usize> {
+        if self.in_bounds(pos) {
+            Some(pos.y as usize * self.width + pos.x as usize)
+        } else {
+            None
+        }
+    }
+
+    pub fn get(&self, pos: impl Into<Point>) -> Option<&T> {
+        self.offset(pos.into()).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: impl Into<Point>) -> Option<&mut T> {
+        self.offset(pos.into()).map(|i| &mut self.cells[i])
+    }
+
+    /// Sets the cell at `pos`, returning the previous value, or `None` if
+    /// `pos` is outside the grid.
+    pub fn set(&mut self, pos: impl Into<Point>, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x as i32, y as i32)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The in-bounds neighbours of `pos` in the given directions.
+    pub fn neighbors<'a>(
+        &'a self,
+        pos: impl Into<Point>,
+        directions: &'a [Direction],
+    ) -> impl Iterator<Item = (Point, &'a T)> + 'a {
+        let pos = pos.into();
+        directions.iter().filter_map(move |&direction| {
+            let next = pos.step(direction);
+            self.get(next).map(|cell| (next, cell))
+        })
+    }
+
+    /// The in-bounds orthogonal neighbours of `pos`.
+    pub fn neighbors4(&self, pos: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors(pos, &Direction::ORTHOGONAL)
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbors8(&self, pos: impl Into<Point>) -> impl Iterator<Item = (Point, &T)> {
+        self.neighbors(pos, &Direction::ALL)
+    }
+
+    /// The cells from `pos` onwards in steps of `step`, until leaving the grid.
+    pub fn ray(&self, pos: impl Into<Point>, step: impl Into<Point>) -> impl Iterator<Item = &T> {
+        let (mut next, step) = (pos.into(), step.into());
+        std::iter::from_fn(move || {
+            let cell = self.get(next)?;
+            next += step;
+            Some(cell)
+        })
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        // `chunks` panics on a zero chunk size, so an empty grid has no rows.
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        self.ray((x as i32, 0), Direction::South)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// The top-left to bottom-right diagonals, starting from the bottom left.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let starts = (0..h).rev().map(|y| (0, y)).chain((1..w).map(|x| (x, 0)));
+        starts.map(|start| self.ray(start, Direction::SouthEast))
+    }
+
+    /// The top-right to bottom-left diagonals, starting from the top left.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        let (w, h) = (self.width as i32, self.height as i32);
+        let starts = (0..w)
+            .map(|x| (x, 0))
+            .chain((1..h).map(move |y| (w - 1, y)));
+        starts.map(|start| self.ray(start, Direction::SouthWest))
+    }
+
+    /// The position of the first cell equal to `value`, row by row.
+    pub fn find(&self, value: &T) -> Option<Point>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, c)| *c == value).map(|(pos, _)| pos)
+    }
+
+    /// The positions of all cells equal to `value`, row by row.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Point> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, c)| *c == value)
+            .map(|(pos, _)| pos)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::from_vec(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl Grid<char> {
+    pub fn parse(input: &str) -> Result<Grid<char>, ParseError> {
+        Grid::parse_with(input, Ok)
+    }
+}
+
+impl Grid<u8> {
+    /// Parses a grid of single decimal digits.
+    pub fn parse_digits(input: &str) -> Result<Grid<u8>, ParseError> {
+        Grid::parse_with(input, |c| {
+            c.to_digit(10)
+                .map(|d| d as u8)
+                .ok_or_else(|| format!("expected a digit, found `{}`", c))
+        })
+    }
+}
+
+impl<T, P: Into<Point>> Index<P> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: P) -> &T {
+        let pos = pos.into();
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T, P: Into<Point>> IndexMut<P> for Grid<T> {
+    fn index_mut(&mut self, pos: P) -> &mut T {
+        let pos = pos.into();
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+/// Prints one row per line, with no separator between cells.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    const TEST_GRID: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse(TEST_GRID).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.to_string(), TEST_GRID);
+    }
+
+    #[test]
+    fn test_parse_ragged_rows() {
+        let err = Grid::parse("abc\nde\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 1));
+    }
+
+    #[test]
+    fn test_parse_digits() {
+        let grid = Grid::parse_digits("01\n23\n").unwrap();
+        assert_eq!(grid[(1, 1)], 3);
+
+        let err = Grid::parse_digits("01\n2x\n").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_signed_get() {
+        let grid = Grid::parse(TEST_GRID).unwrap();
+
+        assert_eq!(grid.get((2, 1)), Some(&'f'));
+        assert_eq!(grid.get((-1, 0)), None);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.get((0, 2)), None);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::parse(TEST_GRID).unwrap();
+
+        let n4: Vec<char> = grid.neighbors4((0, 0)).map(|(_, &c)| c).collect();
+        assert_eq!(n4, vec!['b', 'd']);
+
+        let n8: Vec<char> = grid.neighbors8((1, 0)).map(|(_, &c)| c).collect();
+        assert_eq!(n8, vec!['c', 'f', 'e', 'd', 'a']);
+    }
+
+    #[test]
+    fn test_lines() {
+        let grid = Grid::parse(TEST_GRID).unwrap();
+        let collect = |it: &mut dyn Iterator<Item = &char>| it.collect::<String>();
+
+        assert_eq!(grid.row(1), &['d', 'e', 'f']);
+        let columns: Vec<String> = grid.columns().map(|mut c| collect(&mut c)).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+        let diagonals: Vec<String> = grid.diagonals().map(|mut d| collect(&mut d)).collect();
+        assert_eq!(diagonals, vec!["d", "ae", "bf", "c"]);
+        let anti: Vec<String> = grid.anti_diagonals().map(|mut d| collect(&mut d)).collect();
+        assert_eq!(anti, vec!["a", "bd", "ce", "f"]);
+    }
+
+    #[test]
+    fn test_find_and_set() {
+        let mut grid = Grid::parse("#.#\n.^.\n").unwrap();
+
+        assert_eq!(grid.find(&'^'), Some(Point::new(1, 1)));
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(2, 0)]
+        );
+        assert_eq!(grid.set((1, 1), '.'), Some('^'));
+        assert_eq!(grid.find(&'^'), None);
+    }
+}
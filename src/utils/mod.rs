@@ -0,0 +1,6 @@
+pub mod cycle;
+pub mod graph;
+pub mod grid;
+pub mod parse;
+pub mod point;
+pub mod toposort;
@@ -0,0 +1,212 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+/// An error in puzzle input, located by 1-based line and column.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error at byte `offset` into `input`.
+    pub fn at_offset(input: &str, offset: usize, message: impl Into<String>) -> ParseError {
+        let before = &input[..offset.min(input.len())];
+        let line = before.matches('\n').count() + 1;
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].chars().count() + 1;
+
+        ParseError::new(line, column, message)
+    }
+
+    /// An error at the start of `s`, which must be a slice of `input`.
+    pub fn at(input: &str, s: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, offset_of(input, s), message)
+    }
+
+    /// An error just past the end of `s`, which must be a slice of `input`.
+    /// Used when a field is missing from the end of a line.
+    pub fn after(input: &str, s: &str, message: impl Into<String>) -> ParseError {
+        ParseError::at_offset(input, offset_of(input, s) + s.len(), message)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+fn offset_of(input: &str, s: &str) -> usize {
+    let offset = (s.as_ptr() as usize).wrapping_sub(input.as_ptr() as usize);
+    debug_assert!(offset <= input.len(), "`s` is not a slice of `input`");
+    offset.min(input.len())
+}
+
+/// Parses `s`, a slice of `input`, reporting its location if it is not a valid `T`.
+pub fn parse<T>(input: &str, s: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    s.parse()
+        .map_err(|e| ParseError::at(input, s, format!("invalid value `{}`: {}", s, e)))
+}
+
+/// The fields of `s`, a slice of `input`, split on `separator` and each
+/// parsed as a `T`. A whitespace separator splits on runs of whitespace.
+///
+/// A field that is not a valid `T` is an error, reported at its location, so
+/// a stray character in the input is never silently skipped. Nothing is
+/// allocated.
+pub fn fields<'a, T>(
+    input: &'a str,
+    s: &'a str,
+    separator: char,
+) -> impl Iterator<Item = Result<T, ParseError>> + 'a
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    field_spans(s, separator).map(move |field| parse(input, field))
+}
+
+/// Exactly `N` fields from `s`, a slice of `input`, split on `separator` as
+/// for `fields`, such as the two numbers of `47|53`.
+pub fn split_record<T, const N: usize>(
+    input: &str,
+    s: &str,
+    separator: char,
+) -> Result<[T; N], ParseError>
+where
+    T: FromStr + Copy + Default,
+    T::Err: fmt::Display,
+{
+    let mut spans = field_spans(s, separator);
+    let mut record = [T::default(); N];
+    for (i, field) in record.iter_mut().enumerate() {
+        let span = spans.next().ok_or_else(|| {
+            ParseError::after(input, s, format!("expected {} fields, found {}", N, i))
+        })?;
+        *field = parse(input, span)?;
+    }
+    if let Some(extra) = spans.next() {
+        return Err(ParseError::at(
+            input,
+            extra,
+            format!("expected {} fields, found more", N),
+        ));
+    }
+
+    Ok(record)
+}
+
+fn field_spans(s: &str, separator: char) -> impl Iterator<Item = &str> {
+    let (whitespace, other) = if separator.is_whitespace() {
+        (Some(s.split_whitespace()), None)
+    } else {
+        (None, Some(s.split(separator)))
+    };
+    whitespace
+        .into_iter()
+        .flatten()
+        .chain(other.into_iter().flatten())
+}
+
+/// The blank-line separated sections of `input`, as slices of it without
+/// their surrounding line endings. Lines may end in `\n` or `\r\n`.
+pub fn sections(input: &str) -> impl Iterator<Item = &str> {
+    let mut lines = input.lines().peekable();
+    std::iter::from_fn(move || {
+        let first = lines.find(|line| !line.is_empty())?;
+        let mut last = first;
+        while let Some(line) = lines.next_if(|line| !line.is_empty()) {
+            last = line;
+        }
+        Some(&input[offset_of(input, first)..offset_of(input, last) + last.len()])
+    })
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_error_location() {
+        let input = "1 2\n3 x\n";
+        let field = &input[6..7];
+
+        let err = parse::<u32>(input, field).unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(
+            err.to_string(),
+            "line 2, column 3: invalid value `x`: invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn test_parse_error_after() {
+        let input = "1 2\n3\n";
+        let line = &input[4..5];
+
+        let err = ParseError::after(input, line, "missing number");
+
+        assert_eq!((err.line, err.column), (2, 2));
+    }
+
+    #[test]
+    fn test_fields() {
+        let input = "1  2\t3\n4,,5\n";
+        let mut lines = input.lines();
+
+        let line = lines.next().unwrap();
+        let numbers: Vec<u32> = fields(input, line, ' ').collect::<Result<_, _>>().unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+
+        let line = lines.next().unwrap();
+        let err = fields::<u32>(input, line, ',').nth(1).unwrap().unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+
+    #[test]
+    fn test_split_record() {
+        let input = "47|53\n4x|5\n1|2|3\n";
+        let lines: Vec<&str> = input.lines().collect();
+
+        assert_eq!(split_record::<u32, 2>(input, lines[0], '|'), Ok([47, 53]));
+
+        let err = split_record::<u32, 2>(input, lines[1], '|').unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+
+        let err = split_record::<u32, 2>(input, lines[2], '|').unwrap_err();
+        assert_eq!((err.line, err.column), (3, 5));
+        assert_eq!(err.message, "expected 2 fields, found more");
+    }
+
+    #[test]
+    fn test_sections() {
+        let input = "a\nb\n\nc\n\n\nd\n";
+
+        assert_eq!(sections(input).collect::<Vec<_>>(), vec!["a\nb", "c", "d"]);
+
+        let input = "a\r\nb\r\n\r\nc\r\n";
+        assert_eq!(sections(input).collect::<Vec<_>>(), vec!["a\r\nb", "c"]);
+    }
+}
@@ -0,0 +1,175 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+
+/// The nodes a topological sort could not place because they lie on, or
+/// after, a cycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CycleError<N> {
+    pub remaining: Vec<N>,
+}
+
+impl<N: fmt::Debug> fmt::Display for CycleError<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ordering has a cycle among {:?}", self.remaining)
+    }
+}
+
+/// Orders `nodes` so that for every edge `(a, b)`, `a` comes before `b`,
+/// using Kahn's algorithm.
+///
+/// Edges with an end outside `nodes` are ignored, so a large rule set can be
+/// applied to a few nodes at a time. Whenever several nodes could come next,
+/// the one earliest in `nodes` is taken, so `nodes` that already satisfy every
+/// edge are returned in the same order.
+pub fn toposort<N: Clone + Eq + Hash>(
+    nodes: impl IntoIterator<Item = N>,
+    edges: impl IntoIterator<Item = (N, N)>,
+) -> Result<Vec<N>, CycleError<N>> {
+    let nodes: Vec<N> = nodes.into_iter().collect();
+    let index: HashMap<&N, usize> = nodes.iter().enumerate().map(|(i, n)| (n, i)).collect();
+
+    let mut after = vec![Vec::new(); nodes.len()];
+    let mut indegree = vec![0; nodes.len()];
+    let mut seen = HashSet::new();
+    for (a, b) in edges {
+        if let (Some(&a), Some(&b)) = (index.get(&a), index.get(&b)) {
+            if seen.insert((a, b)) {
+                after[a].push(b);
+                indegree[b] += 1;
+            }
+        }
+    }
+
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..nodes.len())
+        .filter(|&i| indegree[i] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(nodes.len());
+    while let Some(Reverse(i)) = ready.pop() {
+        order.push(nodes[i].clone());
+        for &j in &after[i] {
+            indegree[j] -= 1;
+            if indegree[j] == 0 {
+                ready.push(Reverse(j));
+            }
+        }
+    }
+
+    if order.len() < nodes.len() {
+        let remaining = (0..nodes.len())
+            .filter(|&i| indegree[i] > 0)
+            .map(|i| nodes[i].clone())
+            .collect();
+        return Err(CycleError { remaining });
+    }
+
+    Ok(order)
+}
+
+/// A set of "`a` comes before `b`" rules, such as page ordering rules.
+#[derive(Clone, Debug, Default)]
+pub struct Precedence<N> {
+    rules: HashSet<(N, N)>,
+}
+
+impl<N: Clone + Eq + Hash> Precedence<N> {
+    pub fn new(rules: impl IntoIterator<Item = (N, N)>) -> Precedence<N> {
+        Precedence {
+            rules: rules.into_iter().collect(),
+        }
+    }
+
+    pub fn insert(&mut self, before: N, after: N) {
+        self.rules.insert((before, after));
+    }
+
+    /// Compares two items by the rule between them, or `Equal` if there is
+    /// none.
+    ///
+    /// This is only a total order over items where every pair has a rule, so
+    /// use `sort` rather than `sort_by` when the rules may be incomplete.
+    pub fn compare(&self, a: &N, b: &N) -> Ordering {
+        if self.rules.contains(&(a.clone(), b.clone())) {
+            Ordering::Less
+        } else if self.rules.contains(&(b.clone(), a.clone())) {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }
+    }
+
+    /// Whether no rule is broken by a later item coming before an earlier one.
+    pub fn is_sorted(&self, items: &[N]) -> bool {
+        items.iter().enumerate().all(|(i, a)| {
+            items[i + 1..]
+                .iter()
+                .all(|b| !self.rules.contains(&(b.clone(), a.clone())))
+        })
+    }
+
+    /// `items` reordered to satisfy every rule between them.
+    pub fn sort(&self, items: &[N]) -> Result<Vec<N>, CycleError<N>> {
+        // Looking up each pair of items is far cheaper than scanning every
+        // rule when there are many more rules than items.
+        let edges = items.iter().flat_map(|a| {
+            items
+                .iter()
+                .map(move |b| (a.clone(), b.clone()))
+                .filter(|pair| self.rules.contains(pair))
+        });
+        toposort(items.iter().cloned(), edges)
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+
+    #[test]
+    fn test_toposort() {
+        let order = toposort(['d', 'c', 'b', 'a'], [('a', 'b'), ('b', 'c'), ('x', 'a')]);
+
+        // `d` is unconstrained so stays first; the edge from `x` is ignored.
+        assert_eq!(order, Ok(vec!['d', 'a', 'b', 'c']));
+    }
+
+    #[test]
+    fn test_toposort_takes_earliest_ready_node() {
+        // Once `y` is placed, `x` is free and comes before `z`, as in `nodes`.
+        let order = toposort(['x', 'y', 'z'], [('y', 'x')]);
+        assert_eq!(order, Ok(vec!['y', 'x', 'z']));
+
+        let order = toposort(['a', 'b', 'c', 'd'], [('a', 'c'), ('b', 'd')]);
+        assert_eq!(order, Ok(vec!['a', 'b', 'c', 'd']));
+    }
+
+    #[test]
+    fn test_toposort_cycle() {
+        let err = toposort(
+            ['a', 'b', 'c', 'd'],
+            [('a', 'b'), ('b', 'c'), ('c', 'b'), ('c', 'd')],
+        )
+        .unwrap_err();
+
+        assert_eq!(err.remaining, vec!['b', 'c', 'd']);
+        assert_eq!(
+            err.to_string(),
+            "ordering has a cycle among ['b', 'c', 'd']"
+        );
+    }
+
+    #[test]
+    fn test_precedence() {
+        let rules = Precedence::new([(47, 53), (97, 13), (97, 47), (47, 13), (53, 13)]);
+
+        assert_eq!(rules.compare(&47, &53), Ordering::Less);
+        assert_eq!(rules.compare(&13, &97), Ordering::Greater);
+        assert_eq!(rules.compare(&53, &97), Ordering::Equal);
+
+        assert!(rules.is_sorted(&[97, 47, 53, 13]));
+        assert!(!rules.is_sorted(&[47, 97, 53, 13]));
+        assert_eq!(rules.sort(&[13, 53, 47, 97]), Ok(vec![97, 47, 53, 13]));
+    }
+}